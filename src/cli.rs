@@ -2,7 +2,7 @@ use std::fs;
 use std::path::Path;
 
 use clap::{App, AppSettings, ArgGroup, ArgMatches};
-use serde_json::{Number, Value};
+use serde_json::{Map, Number, Value};
 
 use crate::completion;
 use crate::config::Config;
@@ -164,13 +164,34 @@ fn test_component(m: &ArgMatches) -> Result<()> {
 }
 
 fn add_power(m: &ArgMatches) -> Result<()> {
-    println!("add_power -> {}", m.value_of("NET").unwrap());
-    Ok(())
+    let mut lib = Library::new();
+    let net = m.value_of("NET").unwrap();
+    lib.add_power(net)?;
+
+    Config::create_if_missing(QEDA_YML)?;
+    let mut config = Config::from_yaml_file(QEDA_YML)?;
+    config.insert_object("power", net)?;
+    config.save(QEDA_YML)
 }
 
 fn add_ground(m: &ArgMatches) -> Result<()> {
-    println!("add_ground -> {}", m.value_of("NET").unwrap());
-    Ok(())
+    let mut lib = Library::new();
+    let net = m.value_of("NET").unwrap();
+    let variant = if m.is_present("chassis") {
+        "chassis"
+    } else if m.is_present("earth") {
+        "earth"
+    } else {
+        "signal"
+    };
+    lib.add_ground(net, variant)?;
+
+    Config::create_if_missing(QEDA_YML)?;
+    let mut config = Config::from_yaml_file(QEDA_YML)?;
+    let mut ground = Map::new();
+    ground.insert("type".to_string(), Value::String(variant.to_string()));
+    config.insert_child("ground", net, Value::Object(ground))?;
+    config.save(QEDA_YML)
 }
 
 fn configure(m: &ArgMatches) -> Result<()> {
@@ -21,11 +21,18 @@ impl Component {
             .symbols
             .get_handler(&config.get_string("symbol.type")?)?;
         let symbol = symbol_handler.draw(&config, &lib.config)?;
-        let package_handler = lib
-            .packages
-            .get_handler(&config.get_string("package.type")?)?;
-        let pattern = package_handler.draw_pattern(&config, &lib.config)?;
-        let model = package_handler.draw_model(&config, &lib.config)?;
+        // Power symbols are net labels without any physical package
+        let (pattern, model) = if symbol.power {
+            (Drawing::new(), Drawing::new())
+        } else {
+            let package_handler = lib
+                .packages
+                .get_handler(&config.get_string("package.type")?)?;
+            (
+                package_handler.draw_pattern(&config, &lib.config)?,
+                package_handler.draw_model(&config, &lib.config)?,
+            )
+        };
         let digest = config.calc_digest();
         Ok(Component {
             name,
@@ -9,7 +9,7 @@ use yaml_rust::{yaml, Yaml, YamlEmitter, YamlLoader};
 
 use crate::error::*;
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Range(pub f64, pub f64);
 
 impl Range {
@@ -277,6 +277,11 @@ impl Config {
 
     /// Inserts an object to the `Config`.
     pub fn insert_object(&mut self, key: &str, name: &str) -> Result<()> {
+        self.insert_child(key, name, Value::Object(Map::new()))
+    }
+
+    /// Inserts (or replaces) a named child value of the top-level object.
+    pub fn insert_child(&mut self, key: &str, name: &str, value: Value) -> Result<()> {
         let map = self.json.as_object_mut().unwrap();
         if !map.contains_key(key) {
             // Insert child if doesn't exist
@@ -285,7 +290,7 @@ impl Config {
         let child = map[key]
             .as_object_mut()
             .ok_or_else(|| QedaError::InvalidElementType(key.to_string(), "object"))?;
-        child.insert(name.to_string(), Value::Object(Map::new()));
+        child.insert(name.to_string(), value);
         Ok(())
     }
 
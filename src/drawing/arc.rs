@@ -0,0 +1,96 @@
+use super::{Layer, Point, Transform, Transformation};
+
+#[derive(Clone, Default, Debug)]
+pub struct Arc {
+    pub center: Point,
+    pub radius: f64,
+    pub start: f64,
+    pub end: f64,
+    pub width: f64,
+    pub layer: Layer,
+}
+
+impl Arc {
+    /// Creates a new `Arc` running from the `start` to the `end` angle (in degrees).
+    ///
+    /// Angles are measured from the X axis towards the Y axis.
+    pub fn new(x: f64, y: f64, radius: f64, start: f64, end: f64) -> Self {
+        Arc {
+            center: Point { x, y },
+            radius,
+            start,
+            end,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the start point of the `Arc`.
+    pub fn start_point(&self) -> Point {
+        let a = self.start.to_radians();
+        Point::new(
+            self.center.x + self.radius * a.cos(),
+            self.center.y + self.radius * a.sin(),
+        )
+    }
+
+    /// Returns the end point of the `Arc`.
+    pub fn end_point(&self) -> Point {
+        let a = self.end.to_radians();
+        Point::new(
+            self.center.x + self.radius * a.cos(),
+            self.center.y + self.radius * a.sin(),
+        )
+    }
+
+    /// Builds an `Arc` with modified layer.
+    #[inline]
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds an `Arc` with modified line width.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+impl Transform for Arc {
+    fn transform(mut self, t: &Transformation) -> Self {
+        let angle = |p: &Point, c: &Point| (p.y - c.y).atan2(p.x - c.x).to_degrees();
+        let sweep = self.end - self.start;
+        let start = self.start_point().transform(t);
+        let center = self.center.clone().transform(t);
+        // Mirroring reverses the direction of rotation
+        let origin = Point::new(0.0, 0.0).transform(t);
+        let x = Point::new(1.0, 0.0).transform(t);
+        let y = Point::new(0.0, 1.0).transform(t);
+        let det = (x.x - origin.x) * (y.y - origin.y) - (x.y - origin.y) * (y.x - origin.x);
+        let sweep = if det < 0.0 { -sweep } else { sweep };
+        self.start = angle(&start, &center);
+        self.end = self.start + sweep;
+        if sweep < 0.0 {
+            std::mem::swap(&mut self.start, &mut self.end);
+        }
+        self.center = center;
+        self.radius *= t.scale;
+        self.width *= t.scale;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirror() {
+        let mut t = Transformation::new();
+        t.scale(2.0, -2.0);
+        let arc = Arc::new(0.0, 0.0, 1.0, 0.0, 90.0).transform(&t);
+        assert_eq!(arc.radius, 2.0);
+        assert_eq!((arc.start, arc.end), (-90.0, 0.0));
+    }
+}
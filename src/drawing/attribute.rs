@@ -61,6 +61,13 @@ impl Attribute {
         self.origin.y = y;
         self
     }
+
+    /// Builds an `Attribute` with modified visibility.
+    #[inline]
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
 }
 
 impl Transform for Attribute {
@@ -0,0 +1,51 @@
+use super::{Layer, Point, Transform, Transformation};
+
+#[derive(Clone, Default, Debug)]
+pub struct Circle {
+    pub center: Point,
+    pub radius: f64,
+    pub width: f64,
+    pub filled: bool,
+    pub layer: Layer,
+}
+
+impl Circle {
+    /// Creates a new `Circle`.
+    pub fn new(x: f64, y: f64, radius: f64) -> Self {
+        Circle {
+            center: Point { x, y },
+            radius,
+            ..Self::default()
+        }
+    }
+
+    /// Builds a filled `Circle`.
+    #[inline]
+    pub fn filled(mut self) -> Self {
+        self.filled = true;
+        self
+    }
+
+    /// Builds a `Circle` with modified layer.
+    #[inline]
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds a `Circle` with modified line width.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+impl Transform for Circle {
+    fn transform(mut self, t: &Transformation) -> Self {
+        self.width *= t.scale;
+        self.radius *= t.scale;
+        self.center = self.center.transform(t);
+        self
+    }
+}
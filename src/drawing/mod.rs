@@ -1,10 +1,13 @@
 pub mod prelude;
 
+mod arc;
 mod attribute;
 mod box3d;
+mod circle;
 mod geometry;
 mod line;
 mod pad;
+mod polyline;
 mod rect;
 mod svg;
 mod symbol_pin;
@@ -16,11 +19,14 @@ use crate::pinout::Pinout;
 
 pub use prelude::*;
 
+pub use arc::Arc;
 pub use attribute::Attribute;
 pub use box3d::Box3D;
+pub use circle::Circle;
 pub use geometry::*;
 pub use line::Line;
 pub use pad::*;
+pub use polyline::Polyline;
 pub use rect::Rect;
 pub use symbol_pin::SymbolPin;
 
@@ -28,8 +34,10 @@ use svg::*;
 
 #[derive(Debug)]
 pub enum Element {
+    Arc(Arc),
     Attribute(Attribute),
     Box3D(Box3D),
+    Circle(Circle),
     Line(Line),
     Pad(Pad),
     SymbolPin(SymbolPin),
@@ -38,8 +46,10 @@ pub enum Element {
 impl Transform for Element {
     fn transform(self, t: &Transformation) -> Self {
         match self {
+            Element::Arc(a) => Element::Arc(a.transform(t)),
             Element::Attribute(a) => Element::Attribute(a.transform(t)),
             Element::Box3D(b) => Element::Box3D(b), // Don't apply 2D transformation
+            Element::Circle(c) => Element::Circle(c.transform(t)),
             Element::Line(l) => Element::Line(l.transform(t)),
             Element::Pad(p) => Element::Pad(p.transform(t)),
             Element::SymbolPin(p) => Element::SymbolPin(p.transform(t)),
@@ -95,7 +105,7 @@ impl Drawing {
                 SvgElement::HLine(hline) => {
                     let line = Line::new(hline.x0, hline.y, hline.x1, hline.y);
                     if id.starts_with("pin") {
-                        self.add_symbol_pin(&id, &pinout, line)?;
+                        self.add_svg_pin(&id, &pinout, line)?;
                     } else {
                         self.add_line(line.width(hline.width));
                     }
@@ -103,7 +113,7 @@ impl Drawing {
                 SvgElement::VLine(vline) => {
                     let line = Line::new(vline.x, vline.y0, vline.x, vline.y1);
                     if id.starts_with("pin") {
-                        self.add_symbol_pin(&id, &pinout, line)?;
+                        self.add_svg_pin(&id, &pinout, line)?;
                     } else {
                         self.add_line(line.width(vline.width));
                     }
@@ -111,6 +121,30 @@ impl Drawing {
                 SvgElement::Line(line) => self.add_line(
                     Line::new(line.p.0.x, line.p.0.y, line.p.1.x, line.p.1.y).width(line.width),
                 ),
+                SvgElement::Polygon(polygon) => {
+                    for p in polygon.p.windows(2) {
+                        if let Some(arc) = &p[1].arc {
+                            self.add_arc(
+                                Arc::new(arc.cx, arc.cy, arc.radius, arc.start, arc.end)
+                                    .width(polygon.line_width),
+                            );
+                        } else {
+                            self.add_line(
+                                Line::new(p[0].x, p[0].y, p[1].x, p[1].y).width(polygon.line_width),
+                            );
+                        }
+                    }
+                }
+                SvgElement::Rect(rect) => {
+                    let (x0, y0) = (rect.x, rect.y);
+                    let (x1, y1) = (rect.x + rect.width, rect.y + rect.height);
+                    let corners = [(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)];
+                    for p in corners.windows(2) {
+                        self.add_line(
+                            Line::new(p[0].0, p[0].1, p[1].0, p[1].1).width(rect.line_width),
+                        );
+                    }
+                }
                 SvgElement::Text(text) => {
                     let attr = Attribute::new(&id, &text.text)
                         .origin(text.x, text.y)
@@ -125,6 +159,13 @@ impl Drawing {
         Ok(())
     }
 
+    /// Adds an arc object to the drawing.
+    #[inline]
+    pub fn add_arc(&mut self, arc: Arc) {
+        self.elements
+            .push(Element::Arc(arc.transform(&self.canvas_transform)));
+    }
+
     /// Adds an `Attribute` object to the drawing.
     #[inline]
     pub fn add_attribute(&mut self, attr: Attribute) {
@@ -138,6 +179,13 @@ impl Drawing {
         self.elements.push(Element::Box3D(box3d));
     }
 
+    /// Adds a circle object to the drawing.
+    #[inline]
+    pub fn add_circle(&mut self, circle: Circle) {
+        self.elements
+            .push(Element::Circle(circle.transform(&self.canvas_transform)));
+    }
+
     /// Adds a line object to the drawing.
     #[inline]
     pub fn add_line(&mut self, line: Line) {
@@ -175,8 +223,16 @@ impl Drawing {
         })
     }
 
-    // Add a symbol pin
-    fn add_symbol_pin(&mut self, id: &str, pinout: &Pinout, line: Line) -> Result<()> {
+    /// Adds a symbol pin to the drawing.
+    #[inline]
+    pub fn add_symbol_pin(&mut self, sym_pin: SymbolPin) {
+        self.elements.push(Element::SymbolPin(
+            sym_pin.transform(&self.canvas_transform),
+        ));
+    }
+
+    // Add a symbol pin described by SVG element ID
+    fn add_svg_pin(&mut self, id: &str, pinout: &Pinout, line: Line) -> Result<()> {
         let id_elems: Vec<&str> = id.split(':').collect();
         ensure!(
             id_elems.len() == 3,
@@ -199,10 +255,7 @@ impl Drawing {
         let pin = pinout
             .get_first(name)
             .ok_or_else(|| QedaError::InvalidSvgPinName(name.to_string()))?;
-        let sym_pin =
-            SymbolPin::new(pin.clone(), halign, valign, &line).transform(&self.canvas_transform);
-
-        self.elements.push(Element::SymbolPin(sym_pin));
+        self.add_symbol_pin(SymbolPin::new(pin.clone(), halign, valign, &line));
 
         Ok(())
     }
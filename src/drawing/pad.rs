@@ -3,6 +3,7 @@ use super::*;
 #[derive(Clone, Debug)]
 pub enum PadShape {
     Circle,
+    Oval,
     Rect,
     RoundRect,
 }
@@ -21,8 +22,10 @@ pub struct Pad {
     pub size: Size,
     pub shape: PadShape,
     pub hole: Option<Size>,
+    pub non_plated: bool,
     pub layers: Layer,
     pub mask: f64,
+    pub clearance: Option<f64>,
 }
 
 impl Pad {
@@ -34,6 +37,25 @@ impl Pad {
         }
     }
 
+    /// Builds a `Pad` with modified copper clearance, which overrides the board default.
+    #[inline]
+    pub fn clearance(mut self, clearance: f64) -> Self {
+        self.clearance = Some(clearance);
+        self
+    }
+
+    /// Builds a `Pad` with modified hole size.
+    pub fn hole(mut self, x: f64, y: f64) -> Self {
+        self.hole = Some(Size::new(x, y));
+        self
+    }
+
+    /// Returns `true` if `Pad` is a non-plated through hole.
+    #[inline]
+    pub fn is_npth(&self) -> bool {
+        self.hole.is_some() && self.non_plated
+    }
+
     /// Returns `true` if `Pad` has surface mount type.
     #[inline]
     pub fn is_smd(&self) -> bool {
@@ -54,6 +76,13 @@ impl Pad {
         self
     }
 
+    /// Builds a `Pad` with non-plated hole.
+    #[inline]
+    pub fn non_plated(mut self) -> Self {
+        self.non_plated = true;
+        self
+    }
+
     /// Builds a `Pad` with modified origin.
     pub fn origin(mut self, x: f64, y: f64) -> Self {
         self.origin.x = x;
@@ -80,6 +109,8 @@ impl Transform for Pad {
     fn transform(mut self, t: &Transformation) -> Self {
         self.origin = self.origin.transform(t);
         self.size = self.size.transform(t);
+        self.hole = self.hole.map(|hole| hole.transform(t));
+        self.clearance = self.clearance.map(|clearance| clearance * t.scale);
         // TODO: Consider rotation
         self
     }
@@ -0,0 +1,64 @@
+use super::{Layer, Line, Point, Transform, Transformation};
+
+#[derive(Clone, Default, Debug)]
+pub struct Polyline {
+    pub points: Vec<Point>,
+    pub closed: bool,
+    pub line_width: f64,
+    pub layer: Layer,
+}
+
+impl Polyline {
+    /// Creates a new open `Polyline` passing through the given points.
+    pub fn new(points: Vec<Point>) -> Self {
+        Polyline {
+            points,
+            ..Self::default()
+        }
+    }
+
+    /// Builds a closed `Polyline`, i.e. with the last point connected to the first one.
+    pub fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+
+    /// Builds a `Polyline` with modified layer.
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds a `Polyline` with modified line width.
+    #[inline]
+    pub fn line_width(mut self, width: f64) -> Self {
+        self.line_width = width;
+        self
+    }
+
+    /// Converts the `Polyline` to a vector of `Line`s.
+    pub fn to_lines(&self) -> Vec<Line> {
+        let mut lines: Vec<Line> = self
+            .points
+            .windows(2)
+            .map(|p| Line::new(p[0].x, p[0].y, p[1].x, p[1].y))
+            .collect();
+        if self.closed && self.points.len() > 2 {
+            let first = self.points.first().unwrap();
+            let last = self.points.last().unwrap();
+            lines.push(Line::new(last.x, last.y, first.x, first.y));
+        }
+        lines
+            .into_iter()
+            .map(|line| line.width(self.line_width).layer(self.layer))
+            .collect()
+    }
+}
+
+impl Transform for Polyline {
+    fn transform(mut self, t: &Transformation) -> Self {
+        self.line_width *= t.scale;
+        self.points = self.points.into_iter().map(|p| p.transform(t)).collect();
+        self
+    }
+}
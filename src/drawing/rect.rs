@@ -1,4 +1,4 @@
-use super::{Layer, Line, Point, Transform, Transformation};
+use super::{Layer, Line, Point, Polyline, Transform, Transformation};
 
 #[derive(Clone, Default, Debug)]
 pub struct Rect {
@@ -35,6 +35,22 @@ impl Rect {
         self
     }
 
+    /// Converts the `Rect` to a closed `Polyline` with both left corners chamfered.
+    pub fn to_chamfered_left(&self, chamfer: f64) -> Polyline {
+        let (p0, p1) = (&self.p.0, &self.p.1);
+        Polyline::new(vec![
+            Point::new(p0.x + chamfer, p0.y),
+            Point::new(p1.x, p0.y),
+            Point::new(p1.x, p1.y),
+            Point::new(p0.x + chamfer, p1.y),
+            Point::new(p0.x, p1.y - chamfer),
+            Point::new(p0.x, p0.y + chamfer),
+        ])
+        .closed()
+        .line_width(self.line_width)
+        .layer(self.layer)
+    }
+
     /// Converts the `Rect` to a vector of `Line`s.
     pub fn to_lines(&self) -> Vec<Line> {
         vec![
@@ -10,6 +10,48 @@ pub struct SvgPoint {
     pub x: f64,
     pub y: f64,
     pub marker: bool,
+    pub arc: Option<SvgArc>, // Arc leading to this point, straight line if `None`
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SvgArc {
+    pub cx: f64,
+    pub cy: f64,
+    pub radius: f64,
+    pub start: f64,
+    pub end: f64,
+}
+
+impl SvgArc {
+    // Converts SVG endpoint parameterization to the center one (circular arcs only)
+    fn from_endpoints(p0: (f64, f64), p1: (f64, f64), r: f64, large: bool, sweep: bool) -> Self {
+        let dx = (p0.0 - p1.0) / 2.0;
+        let dy = (p0.1 - p1.1) / 2.0;
+        let d2 = dx * dx + dy * dy;
+        let radius = r.max(d2.sqrt());
+        let k = if d2 > 0.0 {
+            ((radius * radius - d2) / d2).max(0.0).sqrt()
+        } else {
+            0.0
+        };
+        let k = if large != sweep { k } else { -k };
+        let cx = k * dy + (p0.0 + p1.0) / 2.0;
+        let cy = -k * dx + (p0.1 + p1.1) / 2.0;
+        let start = (p0.1 - cy).atan2(p0.0 - cx).to_degrees();
+        let mut delta = (p1.1 - cy).atan2(p1.0 - cx).to_degrees() - start;
+        if sweep && delta < 0.0 {
+            delta += 360.0;
+        } else if !sweep && delta > 0.0 {
+            delta -= 360.0;
+        }
+        SvgArc {
+            cx,
+            cy,
+            radius,
+            start,
+            end: start + delta,
+        }
+    }
 }
 
 #[derive(Default, Debug)]
@@ -120,13 +162,9 @@ impl Svg {
             match id {
                 ElementId::Defs => return Ok(()), // Skip <defs>
                 ElementId::Path => {
-                    let mut path_id = node.id().to_string();
-                    if path_id.is_empty() {
-                        path_id = self.id_counter.to_string();
-                        self.id_counter += 1;
-                    }
+                    let path_id = self.node_id(node);
                     let polygon = self.to_polygon(&node.attributes())?;
-                    if polygon.p.len() == 2 {
+                    if polygon.p.len() == 2 && polygon.p[1].arc.is_none() {
                         if (polygon.p[0].y - polygon.p[1].y).abs() < f64::EPSILON {
                             let line = SvgHLine {
                                 x0: polygon.p[0].x,
@@ -155,12 +193,12 @@ impl Svg {
                     }
                 }
                 ElementId::Rect => {
-                    let rect_id = node.id().to_string();
+                    let rect_id = self.node_id(node);
                     let rect = self.to_rect(&node.attributes())?;
                     self.elements.insert(rect_id, SvgElement::Rect(rect));
                 }
                 ElementId::Ellipse => {
-                    let ellipse_id = node.id().to_string();
+                    let ellipse_id = self.node_id(node);
                     let ellipse = self.to_ellipse(&node.attributes())?;
                     self.elements
                         .insert(ellipse_id, SvgElement::Ellipse(ellipse));
@@ -202,6 +240,17 @@ impl Svg {
         Ok(())
     }
 
+    // Returns node ID or generates a unique one if it is missing
+    fn node_id(&mut self, node: &Node) -> String {
+        let id = node.id().to_string();
+        if id.is_empty() {
+            self.id_counter += 1;
+            (self.id_counter - 1).to_string()
+        } else {
+            id
+        }
+    }
+
     fn convert_units(len: &Length) -> Result<f64> {
         match len.unit {
             LengthUnit::None => Ok(len.num),
@@ -274,6 +323,7 @@ impl Svg {
                                         x,
                                         y,
                                         marker: false,
+                                        arc: None,
                                     });
                                 }
                                 PathSegment::HorizontalLineTo { abs, x } => {
@@ -287,6 +337,7 @@ impl Svg {
                                         x,
                                         y,
                                         marker: false,
+                                        arc: None,
                                     });
                                 }
                                 PathSegment::VerticalLineTo { abs, y } => {
@@ -300,8 +351,47 @@ impl Svg {
                                         x,
                                         y,
                                         marker: false,
+                                        arc: None,
                                     });
                                 }
+                                PathSegment::EllipticalArc {
+                                    abs,
+                                    rx,
+                                    large_arc,
+                                    sweep,
+                                    x,
+                                    y,
+                                    ..
+                                } => {
+                                    let mut x = *x;
+                                    let mut y = *y;
+                                    if !abs {
+                                        x += current_x;
+                                        y += current_y;
+                                    }
+                                    let arc = SvgArc::from_endpoints(
+                                        (current_x, current_y),
+                                        (x, y),
+                                        *rx,
+                                        *large_arc,
+                                        *sweep,
+                                    );
+                                    current_x = x;
+                                    current_y = y;
+                                    result.p.push(SvgPoint {
+                                        x,
+                                        y,
+                                        marker: false,
+                                        arc: Some(arc),
+                                    });
+                                }
+                                PathSegment::ClosePath { .. } => {
+                                    if let Some(first) = result.p.first().cloned() {
+                                        current_x = first.x;
+                                        current_y = first.y;
+                                        result.p.push(SvgPoint { arc: None, ..first });
+                                    }
+                                }
                                 _ => (),
                             }
                         }
@@ -409,3 +499,19 @@ pub fn to_elements(svg: &str) -> Result<SvgHash> {
     svg.add_node(&svg_doc.root())?;
     Ok(svg.elements)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arc_from_endpoints() {
+        let arc = SvgArc::from_endpoints((7.0, 10.0), (8.5, 10.0), 0.75, false, true);
+        assert_eq!((arc.cx, arc.cy, arc.radius), (7.75, 10.0, 0.75));
+        assert_eq!((arc.start, arc.end), (180.0, 360.0));
+
+        let arc = SvgArc::from_endpoints((0.0, 1.0), (1.0, 0.0), 1.0, false, false);
+        assert_eq!((arc.cx, arc.cy), (0.0, 0.0));
+        assert_eq!((arc.start, arc.end), (90.0, 0.0));
+    }
+}
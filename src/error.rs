@@ -20,6 +20,9 @@ pub enum QedaError {
     )]
     InvalidPinCount(String, String),
 
+    #[error("invalid pin count, pinout has {0} pins while package has {1}")]
+    InvalidPinoutPinCount(usize, usize),
+
     #[error("invalid pin name: '{0}'")]
     InvalidPinName(String),
 
@@ -41,6 +44,12 @@ pub enum QedaError {
     #[error("invalid symbol, no parts: '{0}'")]
     InvalidSymbolNoParts(String),
 
+    #[error("invalid symbol style: '{0}'")]
+    InvalidSymbolStyle(String),
+
+    #[error("invalid symbol variant: '{0}'")]
+    InvalidSymbolVariant(String),
+
     #[error("invalid symbol type: '{0}'")]
     InvalidSymbolType(String),
 
@@ -53,12 +62,21 @@ pub enum QedaError {
     #[error("missing element '{0}' in config")]
     MissingElement(String),
 
+    #[error("missing origin of land '{0}' lying outside of the grid")]
+    MissingLandOrigin(String),
+
     #[error("unable to get the project directory")]
     UnableToGetProjectDir,
 
     #[error("unknown config parameter: '{0}'")]
     UnknownConfigParameter(String),
 
+    #[error("unsupported pin count for '{0}' package: {1}")]
+    UnsupportedPinCount(&'static str, usize),
+
+    #[error("unsupported row count for '{0}' package: {1}")]
+    UnsupportedRowCount(&'static str, usize),
+
     #[error("config parameter has unsupported type: '{0}'")]
     UnsupportedConfigParameterType(String),
 
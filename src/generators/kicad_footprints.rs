@@ -14,8 +14,9 @@ impl fmt::Display for PadShape {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             PadShape::Circle => write!(f, "circle"),
+            PadShape::Oval => write!(f, "oval"),
             PadShape::Rect => write!(f, "rect"),
-            PadShape::RoundRect => write!(f, "ruondrect"),
+            PadShape::RoundRect => write!(f, "roundrect"),
         }
     }
 }
@@ -77,7 +78,7 @@ impl fmt::Display for Layer {
 
 impl KicadFootprints {
     pub fn render(self, components: &[Component], _lib_cfg: &Config) -> Result<()> {
-        for component in components {
+        for component in components.iter().filter(|c| !c.symbol.power) {
             let name = &component.name;
             let pattern = &component.pattern;
             info!("  • foorprint: '{}'", name);
@@ -106,6 +107,31 @@ impl KicadFootprints {
                         )?;
                         writeln!(f, "  )")?;
                     }
+                    Element::Arc(a) => {
+                        let start = a.start_point();
+                        writeln!(
+                            f,
+                            "  (fp_arc (start {x:.3} {y:.3}) (end {x1:.3} {y1:.3}) (angle {angle:.1}) (layer {layer}) (width {width:.3}))",
+                            x = a.center.x,
+                            y = a.center.y,
+                            x1 = start.x,
+                            y1 = start.y,
+                            angle = a.end - a.start,
+                            layer = a.layer,
+                            width = a.width,
+                        )?;
+                    }
+                    Element::Circle(c) => {
+                        writeln!(
+                            f,
+                            "  (fp_circle (center {x:.3} {y:.3}) (end {x1:.3} {y:.3}) (layer {layer}) (width {width:.3}))",
+                            x = c.center.x,
+                            y = c.center.y,
+                            x1 = c.center.x + c.radius,
+                            layer = c.layer,
+                            width = c.width,
+                        )?;
+                    }
                     Element::Line(l) => {
                         writeln!(
                             f,
@@ -121,16 +147,31 @@ impl KicadFootprints {
                     Element::Pad(p) => {
                         writeln!(
                             f,
-                            "  (pad {name} {kind} {shape} (at {x:.3} {y:.3}) (size {sx:.3} {sy:.3}) (layers {layers}) (solder_mask_margin {mask:.3}))",
+                            "  (pad \"{name}\" {kind} {shape} (at {x:.3} {y:.3}) (size {sx:.3} {sy:.3}){drill} (layers {layers}) (solder_mask_margin {mask:.3}){clearance})",
                             name = p.name,
-                            kind = if p.is_smd() { "smd" } else { "thru_hole" },
+                            kind = if p.is_smd() {
+                                "smd"
+                            } else if p.is_npth() {
+                                "np_thru_hole"
+                            } else {
+                                "thru_hole"
+                            },
                             shape = p.shape,
                             x = p.origin.x,
                             y = p.origin.y,
                             sx = p.size.x,
                             sy = p.size.y,
+                            drill = match &p.hole {
+                                Some(h) if h.x == h.y => format!(" (drill {:.3})", h.x),
+                                Some(h) => format!(" (drill oval {:.3} {:.3})", h.x, h.y),
+                                None => String::new(),
+                            },
                             layers = p.layers,
                             mask = p.mask,
+                            clearance = match p.clearance {
+                                Some(c) => format!(" (clearance {:.3})", c),
+                                None => String::new(),
+                            },
                         )?;
                     }
                     _ => (),
@@ -145,15 +145,18 @@ impl KicadSymbols {
             }
 
             // Parts
+            // Unit 0 is common to all units, so multiple parts are numbered from 1
+            writeln!(f, "DRAW")?;
+            let multi_unit = symbol.parts.len() > 1;
             for (number, part) in symbol.parts.iter().enumerate() {
-                writeln!(f, "DRAW")?;
+                let unit = if multi_unit { number + 1 } else { 0 };
                 for element in &part.elements {
-                    if let Some(element) = self.element(number, element) {
+                    if let Some(element) = self.element(unit, element) {
                         writeln!(f, "{}", element)?;
                     }
                 }
-                writeln!(f, "ENDDRAW")?;
             }
+            writeln!(f, "ENDDRAW")?;
             writeln!(f, "ENDDEF")?;
         }
 
@@ -178,12 +181,50 @@ impl KicadSymbols {
     }
 
     // Render element to a library file record
-    fn element(&self, number: usize, element: &Element) -> Option<String> {
+    fn element(&self, unit: usize, element: &Element) -> Option<String> {
         match element {
+            Element::Arc(a) => {
+                // KiCad always draws the shortest arc between the end points,
+                // so semicircles are narrowed by 0.1° to avoid ambiguity
+                let mut t1 = (a.start * 10.0).round() as i64;
+                let mut t2 = (a.end * 10.0).round() as i64;
+                if t2 - t1 >= 1800 {
+                    t1 += 1;
+                    t2 -= 1;
+                }
+                let normalize = |t: i64| (t + 1800).rem_euclid(3600) - 1800;
+                let (p0, p1) = (a.start_point(), a.end_point());
+                Some(format!(
+                    "A {posx} {posy} {radius} {t1} {t2} {unit} {convert} {thickness} N \
+                    {x1} {y1} {x2} {y2}",
+                    posx = a.center.x.round(),
+                    posy = a.center.y.round(),
+                    radius = a.radius.round(),
+                    t1 = normalize(t1),
+                    t2 = normalize(t2),
+                    unit = unit,
+                    convert = 1,
+                    thickness = a.width.round(),
+                    x1 = p0.x.round(),
+                    y1 = p0.y.round(),
+                    x2 = p1.x.round(),
+                    y2 = p1.y.round(),
+                ))
+            }
+            Element::Circle(c) => Some(format!(
+                "C {posx} {posy} {radius} {unit} {convert} {thickness} {fill}",
+                posx = c.center.x.round(),
+                posy = c.center.y.round(),
+                radius = c.radius.round(),
+                unit = unit,
+                convert = 1,
+                thickness = c.width.round(),
+                fill = if c.filled { "F" } else { "N" },
+            )),
             Element::Line(l) => Some(format!(
                 "P {points_number} {unit} {convert} {thickness} {x1} {y1} {x2} {y2} N",
                 points_number = 2,
-                unit = number,
+                unit = unit,
                 convert = 1, // 0 if common to the 2 representations, if not 1 or 2
                 thickness = l.width.round(),
                 x1 = l.p.0.x.round(),
@@ -202,8 +243,8 @@ impl KicadSymbols {
                 orientation = sym_pin.direction,
                 snum = self.font_size_pin,  // pin number text size
                 snom = self.font_size_name, // pin name text size
-                unit = number, // 0 if common to all parts. If not, number of the part (1. .n)
-                convert = 1,   // 0 if common to the representations, if not 1 or 2
+                unit = unit, // 0 if common to all parts. If not, number of the part (1. .n)
+                convert = 1, // 0 if common to the representations, if not 1 or 2
                 etype = sym_pin.pin.kind,
                 visibility = match sym_pin.visibility {
                     Visibility(true) => "",
@@ -3,6 +3,8 @@ use std::path::Path;
 use std::str;
 use std::time::Duration;
 
+use serde_json::{json, Value};
+
 use crate::component::Component;
 use crate::config::Config;
 use crate::error::*;
@@ -61,10 +63,21 @@ impl Library {
     /// ```
     pub async fn from_config(config: &Config) -> Result<Self> {
         let mut lib = Library::new().merge_config(config);
-        let components_hash = config.get_object("components")?;
-        let keys = components_hash.keys();
-        for key in keys {
-            lib.add_component(key.as_str()).await?; // TODO: Replace awaiting in loop by `join_all` of `JoinHandle`s
+        if let Ok(components_hash) = config.get_object("components") {
+            for key in components_hash.keys() {
+                lib.add_component(key.as_str()).await?; // TODO: Replace awaiting in loop by `join_all` of `JoinHandle`s
+            }
+        }
+        if let Ok(power_hash) = config.get_object("power") {
+            for net in power_hash.keys() {
+                lib.add_power(net)?;
+            }
+        }
+        if let Ok(ground_hash) = config.get_object("ground") {
+            for (net, ground) in ground_hash {
+                let variant = ground["type"].as_str().unwrap_or("signal");
+                lib.add_ground(net, variant)?;
+            }
         }
         Ok(lib)
     }
@@ -97,6 +110,40 @@ impl Library {
         Ok(())
     }
 
+    /// Adds power supply symbol for the specified net to library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qeda::library::Library;
+    ///
+    /// let mut lib = Library::new();
+    /// lib.add_power("+5V").unwrap();
+    ///
+    /// assert!(lib.components[0].symbol.power);
+    /// ```
+    pub fn add_power(&mut self, net: &str) -> Result<()> {
+        info!("adding power '{}'", net);
+        self.add_net_symbol(net, json!({ "type": "power" }))
+    }
+
+    /// Adds ground symbol (`signal`, `chassis` or `earth`) for the specified net to library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qeda::library::Library;
+    ///
+    /// let mut lib = Library::new();
+    /// lib.add_ground("GND", "earth").unwrap();
+    ///
+    /// assert!(lib.components[0].symbol.power);
+    /// ```
+    pub fn add_ground(&mut self, net: &str, variant: &str) -> Result<()> {
+        info!("adding ground '{}'", net);
+        self.add_net_symbol(net, json!({ "type": "ground", "variant": variant }))
+    }
+
     /// Loads component config from remote repository.
     ///
     /// # Examples
@@ -190,6 +237,16 @@ impl Library {
         }
     }
 
+    // Add symbol-only component named after the net
+    fn add_net_symbol(&mut self, net: &str, symbol: Value) -> Result<()> {
+        let mut config = Config::new();
+        config.insert("name", Value::String(net.to_string()));
+        config.insert("symbol", symbol);
+        let component = Component::from_config(&config, self)?;
+        self.components.push(component);
+        Ok(())
+    }
+
     // Merge the own config with the specified one
     fn merge_config(mut self, config: &Config) -> Self {
         self.config = self.config.merge(config);
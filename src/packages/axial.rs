@@ -0,0 +1,57 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7251, TwoPin};
+
+use super::PackageHandler;
+
+pub struct AxialPackage {}
+
+impl AxialPackage {
+    pub fn new() -> Self {
+        AxialPackage {}
+    }
+}
+
+impl PackageHandler for AxialPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw axial pattern");
+
+        let body_len = comp_cfg.get_range("package.body-length")?;
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+        let lead_diameter = comp_cfg.get_range("package.lead-diameter")?;
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let polarized = comp_cfg.get_bool("package.polarized").unwrap_or(false);
+
+        let mut pad_props = Ipc7251::new()
+            .lead_width(lead_diameter)
+            .settings(lib_cfg)
+            .calc();
+        pad_props.distance = pitch;
+
+        let two_pin = TwoPin::default()
+            .pad_properties(pad_props)
+            .assembly(true)
+            .courtyard(true)
+            .polarized(polarized)
+            .body(body_len.nom(), body_diameter.nom());
+
+        let mut drawing = Drawing::new();
+        two_pin.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw axial model");
+        let body_len = comp_cfg.get_range("package.body-length")?;
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_len.nom() / 2.0, -body_diameter.nom() / 2.0, 0.0)
+                .dimensions(body_len.nom(), body_diameter.nom(), body_diameter.max()),
+        );
+        Ok(drawing)
+    }
+}
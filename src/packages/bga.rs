@@ -0,0 +1,64 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{GridArray, Ipc7351B};
+use crate::pinout::Pinout;
+
+use super::{PackageHandler, PackageType};
+
+pub struct BgaPackage {}
+
+impl BgaPackage {
+    pub fn new() -> Self {
+        BgaPackage {}
+    }
+}
+
+impl PackageHandler for BgaPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw BGA pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let ball_diameter = comp_cfg.get_range("package.ball-diameter")?;
+        let (pitch_x, pitch_y) = comp_cfg.get_pair("package.pitch")?;
+        let rows = comp_cfg.get_u64("package.row-count")? as usize;
+        let columns = comp_cfg.get_u64("package.column-count")? as usize;
+        let depopulated = match comp_cfg.get_element("package.depopulated") {
+            Ok(value) => Pinout::new().parse_number(value)?,
+            Err(_) => Vec::new(),
+        };
+
+        let pad_props = Ipc7351B::new(PackageType::Bga)
+            .lead_width(ball_diameter)
+            .settings(lib_cfg)
+            .calc();
+
+        let grid_array = GridArray::default()
+            .pad_properties(pad_props)
+            .pitch(pitch_x, pitch_y)
+            .rows(rows)
+            .columns(columns)
+            .depopulated(depopulated)
+            .body(body_size_x.nom(), body_size_y.nom());
+
+        let mut drawing = Drawing::new();
+        grid_array.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw BGA model");
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_size_x.nom() / 2.0, -body_size_y.nom() / 2.0, 0.0)
+                .dimensions(body_size_x.nom(), body_size_y.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7351B, TwoPin};
+
+use super::{PackageHandler, PackageType};
+
+pub struct CaePackage {}
+
+impl CaePackage {
+    pub fn new() -> Self {
+        CaePackage {}
+    }
+}
+
+impl PackageHandler for CaePackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw CAE pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let lead_span = comp_cfg.get_range("package.lead-span")?;
+        let lead_len = comp_cfg.get_range("package.lead-length")?;
+        let lead_width = comp_cfg.get_range("package.lead-width")?;
+        let chamfer = comp_cfg
+            .get_f64("package.chamfer")
+            .unwrap_or_else(|_| body_size_x.nom().min(body_size_y.nom()) / 4.0);
+
+        let pad_props = Ipc7351B::new(PackageType::Cae)
+            .lead_span(lead_span)
+            .lead_len(lead_len)
+            .lead_width(lead_width)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        // Pin 1 is the positive one
+        let two_pin = TwoPin::default()
+            .pad_properties(pad_props)
+            .courtyard(true)
+            .polarized(true)
+            .chamfer(chamfer)
+            .body(body_size_x.nom(), body_size_y.nom());
+
+        let mut drawing = Drawing::new();
+        two_pin.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw CAE model");
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_size_x.nom() / 2.0, -body_size_y.nom() / 2.0, 0.0)
+                .dimensions(body_size_x.nom(), body_size_y.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
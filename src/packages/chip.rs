@@ -21,15 +21,7 @@ impl PackageHandler for ChipPackage {
         let body_size_y = comp_cfg.get_range("package.body-size-y")?;
         let body_width = body_size_x.nom();
         let body_height = body_size_y.nom();
-        let body_size_z = if let Ok(z) = comp_cfg.get_range("package.body-size-z") {
-            z
-        } else if let Ok(z) = comp_cfg.get_range("package.size-z") {
-            z
-        } else {
-            bail!(QedaError::MissingDimension(
-                "'package' should have either 'body_size_z' or 'size_z'"
-            ));
-        };
+        let body_size_z = super::body_size_z(comp_cfg)?;
         let lead_len = comp_cfg.get_range("package.lead-length")?;
 
         let pad_props = Ipc7351B::new(PackageType::Chip)
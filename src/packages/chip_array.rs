@@ -0,0 +1,64 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7351B, MultiRow};
+
+use super::{PackageHandler, PackageType};
+
+pub struct ChipArrayPackage {}
+
+impl ChipArrayPackage {
+    pub fn new() -> Self {
+        ChipArrayPackage {}
+    }
+}
+
+impl PackageHandler for ChipArrayPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw chip array pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let lead_span = comp_cfg
+            .get_range("package.lead-span")
+            .unwrap_or_else(|_| body_size_x.clone());
+        let lead_len = comp_cfg.get_range("package.lead-length")?;
+        let lead_width = comp_cfg.get_range("package.lead-width")?;
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let pin_count = comp_cfg.get_u64("package.pin-count")? as usize;
+
+        let pad_props = Ipc7351B::new(PackageType::ChipArray)
+            .lead_span(lead_span)
+            .lead_len(lead_len)
+            .lead_width(lead_width)
+            .pitch(pitch)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let multi_row = MultiRow::default()
+            .pad_properties(pad_props)
+            .pitch(pitch)
+            .row_len(pin_count / 2)
+            .body(body_size_x.nom(), body_size_y.nom());
+
+        let mut drawing = Drawing::new();
+        multi_row.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw chip array model");
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_size_x.nom() / 2.0, -body_size_y.nom() / 2.0, 0.0)
+                .dimensions(body_size_x.nom(), body_size_y.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,90 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7351B, MultiRow, TwoPin};
+
+use super::{PackageHandler, PackageType};
+
+pub struct CrystalPackage {}
+
+impl CrystalPackage {
+    pub fn new() -> Self {
+        CrystalPackage {}
+    }
+}
+
+impl PackageHandler for CrystalPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw crystal pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let lead_span = comp_cfg
+            .get_range("package.lead-span")
+            .unwrap_or_else(|_| body_size_x.clone());
+        let lead_len = comp_cfg.get_range("package.lead-length")?;
+        let lead_width = comp_cfg.get_range("package.lead-width")?;
+        let pin_count = comp_cfg.get_u64("package.pin-count")? as usize;
+        let names = super::pad_names(comp_cfg, pin_count)?;
+
+        // Leads sticking out of the body (HC-49 SMD) get gull-wing goals,
+        // terminations of ceramic packages get no-lead ones
+        let package_type = if lead_span.nom() > body_size_x.nom() {
+            PackageType::GullWing
+        } else {
+            PackageType::Son
+        };
+        let mut ipc = Ipc7351B::new(package_type)
+            .lead_span(lead_span)
+            .lead_len(lead_len)
+            .lead_width(lead_width);
+        if let Ok(pitch) = comp_cfg.get_f64("package.pitch") {
+            ipc = ipc.pitch(pitch);
+        }
+        let pad_props = ipc.settings(lib_cfg).calc().post_proc(comp_cfg, lib_cfg);
+
+        let mut drawing = Drawing::new();
+        match pin_count {
+            2 => TwoPin::default()
+                .pad_properties(pad_props)
+                .assembly(true)
+                .courtyard(true)
+                .names(&names[0], &names[1])
+                .body(body_size_x.nom(), body_size_y.nom())
+                .draw(&mut drawing, lib_cfg),
+            // Pin 1 is at the bottom left corner, numbering runs counter-clockwise.
+            // Its pad is the only rectangular one to make the arrangement asymmetric.
+            4 => MultiRow::default()
+                .pad_properties(pad_props)
+                .rounded_pads()
+                .pitch(comp_cfg.get_f64("package.pitch")?)
+                .row_len(2)
+                .names(vec![
+                    names[3].clone(),
+                    names[0].clone(),
+                    names[1].clone(),
+                    names[2].clone(),
+                ])
+                .pin1(&names[0])
+                .body(body_size_x.nom(), body_size_y.nom())
+                .draw(&mut drawing, lib_cfg),
+            _ => bail!(QedaError::UnsupportedPinCount("crystal", pin_count)),
+        }
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw crystal model");
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_size_x.nom() / 2.0, -body_size_y.nom() / 2.0, 0.0)
+                .dimensions(body_size_x.nom(), body_size_y.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
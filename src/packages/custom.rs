@@ -0,0 +1,212 @@
+use serde_json::Value;
+
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing, Layer, Pad, PadShape, Point, Polyline};
+use crate::error::*;
+use crate::pattern::{Custom, Ipc7351B};
+
+use super::{PackageHandler, PackageType};
+
+pub struct CustomPackage {}
+
+impl CustomPackage {
+    pub fn new() -> Self {
+        CustomPackage {}
+    }
+}
+
+impl PackageHandler for CustomPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw custom pattern");
+
+        let pads = match comp_cfg.get_element("package.pads")? {
+            Value::Array(pads) => pads
+                .iter()
+                .enumerate()
+                .map(|(i, pad)| parse_pad(i, pad))
+                .collect::<Result<Vec<Pad>>>()?,
+            _ => bail!(QedaError::InvalidElementType(
+                "package.pads".to_string(),
+                "array"
+            )),
+        };
+
+        let courtyard_excess = Ipc7351B::new(PackageType::Unknown)
+            .settings(lib_cfg)
+            .courtyard();
+        let mut custom = Custom::default()
+            .pads(pads)
+            .silkscreen(parse_polylines(comp_cfg, "package.silkscreen")?)
+            .assembly(parse_polylines(comp_cfg, "package.assembly")?)
+            .courtyard(parse_polylines(comp_cfg, "package.courtyard")?)
+            .courtyard_excess(courtyard_excess);
+        if let (Ok(body_size_x), Ok(body_size_y)) = (
+            comp_cfg.get_range("package.body-size-x"),
+            comp_cfg.get_range("package.body-size-y"),
+        ) {
+            custom = custom.body(body_size_x.nom(), body_size_y.nom());
+        }
+
+        let mut drawing = Drawing::new();
+        custom.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw custom model");
+        let mut drawing = Drawing::new();
+        if let (Ok(body_size_x), Ok(body_size_y), Ok(body_size_z)) = (
+            comp_cfg.get_range("package.body-size-x"),
+            comp_cfg.get_range("package.body-size-y"),
+            super::body_size_z(comp_cfg),
+        ) {
+            drawing.add_box3d(
+                Box3D::new()
+                    .origin(-body_size_x.nom() / 2.0, -body_size_y.nom() / 2.0, 0.0)
+                    .dimensions(body_size_x.nom(), body_size_y.nom(), body_size_z.max()),
+            );
+        }
+        Ok(drawing)
+    }
+}
+
+// Parse a pad description, pads without a name are numbered in the order of appearance
+fn parse_pad(index: usize, value: &Value) -> Result<Pad> {
+    let name = match &value["name"] {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.as_f64().unwrap().to_string(),
+        _ => (index + 1).to_string(),
+    };
+    let (x, y) = super::pair(&value["at"], "package.pads.at")?
+        .ok_or_else(|| QedaError::MissingElement("package.pads.at".to_string()))?;
+    let (size_x, size_y) = match &value["size"] {
+        Value::Number(n) => (n.as_f64().unwrap(), n.as_f64().unwrap()),
+        value => super::pair(value, "package.pads.size")?
+            .ok_or_else(|| QedaError::MissingElement("package.pads.size".to_string()))?,
+    };
+    let hole = match &value["drill"] {
+        Value::Number(n) => Some((n.as_f64().unwrap(), n.as_f64().unwrap())),
+        value => super::pair(value, "package.pads.drill")?,
+    };
+
+    let mut pad = Pad::new(&name).origin(x, y).size(size_x, size_y);
+    let mut layers = Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP;
+    let mut shape = PadShape::Rect;
+    if let Some((hole_x, hole_y)) = hole {
+        pad = pad.hole(hole_x, hole_y);
+        if value["plated"] == Value::Bool(false) {
+            pad = pad.non_plated();
+        }
+        layers = Layer::COPPER_TOP | Layer::COPPER_BOTTOM | Layer::MASK_TOP | Layer::MASK_BOTTOM;
+        shape = PadShape::Circle;
+    }
+    if let Some(s) = value["shape"].as_str() {
+        shape = match s {
+            "circle" => PadShape::Circle,
+            "oval" => PadShape::Oval,
+            "rect" => PadShape::Rect,
+            "roundrect" => PadShape::RoundRect,
+            _ => bail!(QedaError::InvalidElementType(
+                "package.pads.shape".to_string(),
+                "circle, oval, rect or roundrect"
+            )),
+        };
+    }
+    if let Value::Array(names) = &value["layers"] {
+        layers = Layer::NONE;
+        for name in names {
+            layers |= match name.as_str().unwrap_or_default() {
+                "copper-top" => Layer::COPPER_TOP,
+                "copper-bottom" => Layer::COPPER_BOTTOM,
+                "mask-top" => Layer::MASK_TOP,
+                "mask-bottom" => Layer::MASK_BOTTOM,
+                "paste-top" => Layer::PASTE_TOP,
+                "paste-bottom" => Layer::PASTE_BOTTOM,
+                _ => bail!(QedaError::InvalidElementType(
+                    "package.pads.layers".to_string(),
+                    "copper-top, copper-bottom, mask-top, mask-bottom, paste-top or paste-bottom"
+                )),
+            };
+        }
+    }
+    Ok(pad.shape(shape).layers(layers))
+}
+
+// Parse a list of polylines, each one is a list of `[x, y]` points
+fn parse_polylines(comp_cfg: &Config, key: &str) -> Result<Vec<Polyline>> {
+    let polylines = match comp_cfg.get_element(key) {
+        Ok(Value::Array(polylines)) => polylines,
+        Ok(_) => bail!(QedaError::InvalidElementType(
+            key.to_string(),
+            "array of polylines"
+        )),
+        Err(_) => return Ok(Vec::new()),
+    };
+    let mut result = Vec::new();
+    for polyline in polylines {
+        let points = match polyline {
+            Value::Array(points) => points
+                .iter()
+                .map(|point| {
+                    super::pair(point, key)?
+                        .map(|(x, y)| Point::new(x, y))
+                        .ok_or_else(|| {
+                            QedaError::InvalidElementType(key.to_string(), "point").into()
+                        })
+                })
+                .collect::<Result<Vec<Point>>>()?,
+            _ => bail!(QedaError::InvalidElementType(
+                key.to_string(),
+                "array of polylines"
+            )),
+        };
+        result.push(Polyline::new(points));
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawing::Element;
+
+    #[test]
+    fn pad() -> Result<()> {
+        let value: Value = serde_json::from_str(r#"{"at": [1, 2], "size": 1.5, "drill": 0.8}"#)?;
+        let pad = parse_pad(2, &value)?;
+        assert_eq!(pad.name, "3");
+        assert_eq!((pad.origin.x, pad.origin.y), (1.0, 2.0));
+        assert_eq!((pad.size.x, pad.size.y), (1.5, 1.5));
+        assert!(!pad.is_smd() && !pad.is_npth());
+        assert!(pad.layers.contains(Layer::COPPER_BOTTOM));
+
+        let value: Value = serde_json::from_str(r#"{"name": "A", "size": [1, 2]}"#)?;
+        assert!(parse_pad(0, &value).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn pad_origins() -> Result<()> {
+        let comp_cfg = Config::from_yaml(
+            r"
+        package:
+          pads:
+            - {at: [-0.635, 0], size: [0.6, 1.5]}
+            - {at: [0.635, 0], size: [0.6, 1.5]}
+            - {at: [1.905, 0], size: [0.6, 1.5]}
+        ",
+        )?;
+        let lib_cfg = load_config!("../qeda.yml");
+        let drawing = CustomPackage::new().draw_pattern(&comp_cfg, &lib_cfg)?;
+        let origins: Vec<f64> = drawing
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) => Some(pad.origin.x),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(origins, vec![-0.635, 0.635, 1.905]);
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7251, MultiRow};
+
+use super::PackageHandler;
+
+pub struct DipPackage {}
+
+impl DipPackage {
+    pub fn new() -> Self {
+        DipPackage {}
+    }
+}
+
+impl PackageHandler for DipPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw DIP pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let row_pitch = comp_cfg.get_f64("package.row-pitch")?;
+        let lead_width = comp_cfg.get_range("package.lead-width")?;
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let pin_count = comp_cfg.get_u64("package.pin-count")? as usize;
+
+        let mut ipc = Ipc7251::new().lead_width(lead_width);
+        if let Ok(lead_height) = comp_cfg.get_range("package.lead-height") {
+            ipc = ipc.lead_height(lead_height);
+        }
+        let mut pad_props = ipc.settings(lib_cfg).calc();
+        pad_props.distance = row_pitch;
+
+        let multi_row = MultiRow::default()
+            .pad_properties(pad_props)
+            .pitch(pitch)
+            .row_len(pin_count / 2)
+            .body(body_size_x.nom(), body_size_y.nom());
+
+        let mut drawing = Drawing::new();
+        multi_row.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw DIP model");
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_size_x.nom() / 2.0, -body_size_y.nom() / 2.0, 0.0)
+                .dimensions(body_size_x.nom(), body_size_y.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7251, Ipc7351B, PinHeader};
+
+use super::{PackageHandler, PackageType};
+
+pub struct HeaderPackage {}
+
+impl HeaderPackage {
+    pub fn new() -> Self {
+        HeaderPackage {}
+    }
+}
+
+impl PackageHandler for HeaderPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw header pattern");
+
+        let pin_header = pin_header(comp_cfg)?;
+        let rows = comp_cfg.get_u64("package.rows").unwrap_or(1) as usize;
+        let lead_width = comp_cfg.get_range("package.lead-width")?;
+
+        // Leads with a span are bent for surface mounting
+        let pad_props = if let Ok(lead_span) = comp_cfg.get_range("package.lead-span") {
+            ensure!(rows <= 2, QedaError::UnsupportedRowCount("header", rows));
+            let lead_len = comp_cfg.get_range("package.lead-length")?;
+            Ipc7351B::new(PackageType::GullWing)
+                .lead_span(lead_span)
+                .lead_len(lead_len)
+                .lead_width(lead_width)
+                .pitch(comp_cfg.get_f64("package.pitch")?)
+                .settings(lib_cfg)
+                .calc()
+                .post_proc(comp_cfg, lib_cfg)
+        } else {
+            let mut ipc = Ipc7251::new().lead_width(lead_width);
+            if let Ok(lead_height) = comp_cfg.get_range("package.lead-height") {
+                ipc = ipc.lead_height(lead_height);
+            }
+            ipc.settings(lib_cfg).calc()
+        };
+
+        let (body_width, body_height) = body_size(comp_cfg)?;
+        let pin_header = pin_header
+            .pad_properties(pad_props)
+            .body(body_width, body_height);
+
+        let mut drawing = Drawing::new();
+        pin_header.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw header model");
+        let (body_width, body_height) = body_size(comp_cfg)?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_width / 2.0, -body_height / 2.0, 0.0)
+                .dimensions(body_width, body_height, body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
+
+/// Creates a `PinHeader` with the pin layout from the component config.
+pub fn pin_header(comp_cfg: &Config) -> Result<PinHeader> {
+    let rows = comp_cfg.get_u64("package.rows").unwrap_or(1) as usize;
+    let columns = comp_cfg.get_u64("package.columns")? as usize;
+    let pitch = comp_cfg.get_f64("package.pitch")?;
+    let row_major = match comp_cfg.get_str("package.numbering") {
+        Ok("row-major") => true,
+        Ok("zigzag") | Err(_) => false,
+        Ok(_) => bail!(QedaError::InvalidElementType(
+            "package.numbering".to_string(),
+            "'zigzag' or 'row-major'"
+        )),
+    };
+    Ok(PinHeader::default()
+        .rows(rows)
+        .columns(columns)
+        .pitch(pitch)
+        .row_major(row_major))
+}
+
+// Shroud size if specified, plastic strip under the pins otherwise
+fn body_size(comp_cfg: &Config) -> Result<(f64, f64)> {
+    let rows = comp_cfg.get_u64("package.rows").unwrap_or(1) as f64;
+    let columns = comp_cfg.get_u64("package.columns")? as f64;
+    let pitch = comp_cfg.get_f64("package.pitch")?;
+    let width = comp_cfg
+        .get_range("package.body-size-x")
+        .map(|r| r.nom())
+        .unwrap_or(columns * pitch);
+    let height = comp_cfg
+        .get_range("package.body-size-y")
+        .map(|r| r.nom())
+        .unwrap_or(rows * pitch);
+    Ok((width, height))
+}
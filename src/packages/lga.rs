@@ -0,0 +1,82 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing, PadShape};
+use crate::error::*;
+use crate::pattern::{GridArray, Ipc7351B};
+use crate::pinout::Pinout;
+
+use super::{PackageHandler, PackageType};
+
+pub struct LgaPackage {}
+
+impl LgaPackage {
+    pub fn new() -> Self {
+        LgaPackage {}
+    }
+}
+
+impl PackageHandler for LgaPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw LGA pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let (pitch_x, pitch_y) = comp_cfg.get_pair("package.pitch")?;
+        let rows = comp_cfg.get_u64("package.row-count")? as usize;
+        let columns = comp_cfg.get_u64("package.column-count")? as usize;
+        let depopulated = match comp_cfg.get_element("package.depopulated") {
+            Ok(value) => Pinout::new().parse_number(value)?,
+            Err(_) => Vec::new(),
+        };
+
+        // Round terminals (e.g. columns of CGA) get round lands
+        let (pad_props, shape) = match comp_cfg.get_range("package.lead-diameter") {
+            Ok(lead_diameter) => (
+                Ipc7351B::new(PackageType::Lga)
+                    .lead_width(lead_diameter.clone())
+                    .lead_len(lead_diameter)
+                    .settings(lib_cfg)
+                    .calc(),
+                PadShape::Circle,
+            ),
+            Err(_) => (
+                Ipc7351B::new(PackageType::Lga)
+                    .lead_width(comp_cfg.get_range("package.lead-width")?)
+                    .lead_len(comp_cfg.get_range("package.lead-length")?)
+                    .settings(lib_cfg)
+                    .calc(),
+                PadShape::Rect,
+            ),
+        };
+
+        let mut grid_array = GridArray::default()
+            .pad_properties(pad_props)
+            .shape(shape)
+            .pitch(pitch_x, pitch_y)
+            .rows(rows)
+            .columns(columns)
+            .depopulated(depopulated)
+            .body(body_size_x.nom(), body_size_y.nom());
+        for (name, origin, size) in super::grid_lands(comp_cfg, rows, columns)? {
+            grid_array = grid_array.land(&name, origin, size);
+        }
+
+        let mut drawing = Drawing::new();
+        grid_array.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw LGA model");
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_size_x.nom() / 2.0, -body_size_y.nom() / 2.0, 0.0)
+                .dimensions(body_size_x.nom(), body_size_y.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
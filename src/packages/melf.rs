@@ -0,0 +1,59 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7351B, TwoPin};
+
+use super::{PackageHandler, PackageType};
+
+pub struct MelfPackage {}
+
+impl MelfPackage {
+    pub fn new() -> Self {
+        MelfPackage {}
+    }
+}
+
+impl PackageHandler for MelfPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw MELF pattern");
+
+        let body_len = comp_cfg.get_range("package.body-length")?;
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+        let lead_len = comp_cfg.get_range("package.lead-length")?;
+        let polarized = comp_cfg.get_bool("package.polarized").unwrap_or(false);
+
+        // End caps are the terminals, so the body length is the lead span
+        let pad_props = Ipc7351B::new(PackageType::Melf)
+            .lead_span(body_len.clone())
+            .lead_len(lead_len)
+            .lead_width(body_diameter.clone())
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let two_pin = TwoPin::default()
+            .pad_properties(pad_props)
+            .assembly(true)
+            .courtyard(true)
+            .cathode(polarized)
+            .body(body_len.nom(), body_diameter.nom());
+
+        let mut drawing = Drawing::new();
+        two_pin.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw MELF model");
+        let body_len = comp_cfg.get_range("package.body-length")?;
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_len.nom() / 2.0, -body_diameter.nom() / 2.0, 0.0)
+                .dimensions(body_len.nom(), body_diameter.nom(), body_diameter.max()),
+        );
+        Ok(drawing)
+    }
+}
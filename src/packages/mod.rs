@@ -1,18 +1,88 @@
+mod axial;
+mod bga;
+mod cae;
 mod chip;
+mod chip_array;
+mod crystal;
+mod custom;
+mod dip;
+mod header;
+mod lga;
+mod melf;
+mod molded;
+mod mounting_hole;
+mod pak;
+mod pga;
+mod plcc;
+mod qfn;
+mod qfp;
+mod radial;
+mod sod;
+mod soic;
+mod soj;
+mod son;
+mod sot23;
+mod test_point;
+mod to92;
+mod to_can;
+mod to_flange;
 
 use std::collections::HashMap;
 use std::fmt::{self, Debug};
 
-use crate::config::Config;
-use crate::drawing::Drawing;
+use serde_json::Value;
+
+use crate::config::{Config, Range};
+use crate::drawing::{Drawing, Point, Size};
 use crate::error::*;
+use crate::pattern::Land;
+use crate::pinout::Pinout;
+
+pub use header::pin_header;
 
+use axial::AxialPackage;
+use bga::BgaPackage;
+use cae::CaePackage;
 use chip::ChipPackage;
+use chip_array::ChipArrayPackage;
+use crystal::CrystalPackage;
+use custom::CustomPackage;
+use dip::DipPackage;
+use header::HeaderPackage;
+use lga::LgaPackage;
+use melf::MelfPackage;
+use molded::MoldedPackage;
+use mounting_hole::MountingHolePackage;
+use pak::PakPackage;
+use pga::PgaPackage;
+use plcc::PlccPackage;
+use qfn::QfnPackage;
+use qfp::QfpPackage;
+use radial::RadialPackage;
+use sod::SodPackage;
+use soic::SoicPackage;
+use soj::SojPackage;
+use son::SonPackage;
+use sot23::Sot23Package;
+use test_point::TestPointPackage;
+use to92::To92Package;
+use to_can::ToCanPackage;
+use to_flange::ToFlangePackage;
 
 #[derive(Debug)]
 pub enum PackageType {
     Unknown,
+    Bga,
+    Cae,
     Chip,
+    ChipArray,
+    GullWing,
+    JLead,
+    Lga,
+    Melf,
+    Molded,
+    Qfn,
+    Son,
 }
 
 impl Default for PackageType {
@@ -42,7 +112,52 @@ impl Packages {
     /// Creates an empty `Packages`.
     pub fn new() -> Self {
         let mut handlers: HashMap<&'static str, Box<dyn PackageHandler>> = HashMap::new();
+        handlers.insert("axial", Box::new(AxialPackage::new()));
+        handlers.insert("bga", Box::new(BgaPackage::new()));
+        handlers.insert("cae", Box::new(CaePackage::new()));
+        handlers.insert("cga", Box::new(LgaPackage::new()));
         handlers.insert("chip", Box::new(ChipPackage::new()));
+        handlers.insert("chip-array", Box::new(ChipArrayPackage::new()));
+        handlers.insert("crystal", Box::new(CrystalPackage::new()));
+        handlers.insert("custom", Box::new(CustomPackage::new()));
+        handlers.insert("d2pak", Box::new(PakPackage::new()));
+        handlers.insert("dfn", Box::new(SonPackage::new()));
+        handlers.insert("dip", Box::new(DipPackage::new()));
+        handlers.insert("dpak", Box::new(PakPackage::new()));
+        handlers.insert("header", Box::new(HeaderPackage::new()));
+        handlers.insert("lga", Box::new(LgaPackage::new()));
+        handlers.insert("melf", Box::new(MelfPackage::new()));
+        handlers.insert("minimelf", Box::new(MelfPackage::new()));
+        handlers.insert("molded", Box::new(MoldedPackage::new()));
+        handlers.insert("mounting-hole", Box::new(MountingHolePackage::new()));
+        handlers.insert("msop", Box::new(SoicPackage::new()));
+        handlers.insert("oscillator", Box::new(CrystalPackage::new()));
+        handlers.insert("pak", Box::new(PakPackage::new()));
+        handlers.insert("pga", Box::new(PgaPackage::new()));
+        handlers.insert("plcc", Box::new(PlccPackage::new()));
+        handlers.insert("qfn", Box::new(QfnPackage::new()));
+        handlers.insert("qfp", Box::new(QfpPackage::new()));
+        handlers.insert("radial", Box::new(RadialPackage::new()));
+        handlers.insert("sip", Box::new(HeaderPackage::new()));
+        handlers.insert("sma", Box::new(MoldedPackage::new().polarized(true)));
+        handlers.insert("smb", Box::new(MoldedPackage::new().polarized(true)));
+        handlers.insert("smc", Box::new(MoldedPackage::new().polarized(true)));
+        handlers.insert("sod", Box::new(SodPackage::new()));
+        handlers.insert("soic", Box::new(SoicPackage::new()));
+        handlers.insert("soj", Box::new(SojPackage::new()));
+        handlers.insert("son", Box::new(SonPackage::new()));
+        handlers.insert("sop", Box::new(SoicPackage::new()));
+        handlers.insert("sot223", Box::new(PakPackage::new()));
+        handlers.insert("sot23", Box::new(Sot23Package::new()));
+        handlers.insert("ssop", Box::new(SoicPackage::new()));
+        handlers.insert("test-point", Box::new(TestPointPackage::new()));
+        handlers.insert("to18", Box::new(ToCanPackage::new()));
+        handlers.insert("to220", Box::new(ToFlangePackage::new()));
+        handlers.insert("to247", Box::new(ToFlangePackage::new()));
+        handlers.insert("to39", Box::new(ToCanPackage::new()));
+        handlers.insert("to5", Box::new(ToCanPackage::new()));
+        handlers.insert("to92", Box::new(To92Package::new()));
+        handlers.insert("tssop", Box::new(SoicPackage::new()));
 
         Packages { handlers }
     }
@@ -62,3 +177,111 @@ impl Default for Packages {
         Self::new()
     }
 }
+
+// Get the body height, which can be specified either by `body-size-z` or by `size-z`
+fn body_size_z(comp_cfg: &Config) -> Result<Range> {
+    if let Ok(z) = comp_cfg.get_range("package.body-size-z") {
+        Ok(z)
+    } else if let Ok(z) = comp_cfg.get_range("package.size-z") {
+        Ok(z)
+    } else {
+        bail!(QedaError::MissingDimension(
+            "'package' should have either 'body_size_z' or 'size_z'"
+        ));
+    }
+}
+
+// Get land overrides from `package.lands`, keys are land names or ranges like `A1..A3`,
+// values may specify land origin `at: [x, y]` and size `size: [x, y]`
+fn grid_lands(comp_cfg: &Config, rows: usize, columns: usize) -> Result<Vec<Land>> {
+    let mut result = Vec::new();
+    let lands = match comp_cfg.get_object("package.lands") {
+        Ok(lands) => lands,
+        Err(_) => return Ok(result),
+    };
+    let pinout = Pinout::new();
+    for (key, value) in lands {
+        let origin = pair(&value["at"], "package.lands.at")?.map(|(x, y)| Point::new(x, y));
+        let size = pair(&value["size"], "package.lands.size")?.map(|(x, y)| Size::new(x, y));
+        for name in pinout.parse_number(&Value::String(key.clone()))? {
+            let on_grid = (0..rows).any(|row| {
+                let row_name = pinout.row_name(row).unwrap_or_default();
+                (1..=columns).any(|col| format!("{}{}", row_name, col) == name)
+            });
+            ensure!(
+                on_grid || origin.is_some(),
+                QedaError::MissingLandOrigin(name)
+            );
+            result.push((name, origin.clone(), size.clone()));
+        }
+    }
+    Ok(result)
+}
+
+// Get an optional `[x, y]` pair from a config value, `path` is used in error messages
+fn pair(value: &Value, path: &str) -> Result<Option<(f64, f64)>> {
+    match value {
+        Value::Null => Ok(None),
+        Value::Array(a) if a.len() == 2 && a.iter().all(|v| v.is_number()) => {
+            Ok(Some((a[0].as_f64().unwrap(), a[1].as_f64().unwrap())))
+        }
+        _ => Err(QedaError::InvalidElementType(path.to_string(), "pair: [f64, f64]").into()),
+    }
+}
+
+// Get pad names, which are pin numbers from the pinout in ascending order or `1..=count` without it
+fn pad_names(comp_cfg: &Config, count: usize) -> Result<Vec<String>> {
+    let pinout = Pinout::from_config(comp_cfg)?;
+    if pinout.pins.is_empty() {
+        return Ok((1..=count).map(|i| i.to_string()).collect());
+    }
+    let mut numbers: Vec<String> = pinout.pins.iter().map(|pin| pin.number.clone()).collect();
+    numbers.sort_by_key(|number| {
+        (
+            number.parse::<usize>().unwrap_or(usize::MAX),
+            number.clone(),
+        )
+    });
+    numbers.dedup();
+    ensure!(
+        numbers.len() == count,
+        QedaError::InvalidPinoutPinCount(numbers.len(), count)
+    );
+    Ok(numbers)
+}
+
+// Get pin counts along X (top and bottom sides) and along Y (left and right sides).
+// Falls back to the same count at every side if only `pin-count` is specified.
+fn pin_counts_xy(comp_cfg: &Config) -> Result<(usize, usize)> {
+    if let (Ok(x), Ok(y)) = (
+        comp_cfg.get_u64("package.pin-count-x"),
+        comp_cfg.get_u64("package.pin-count-y"),
+    ) {
+        Ok((x as usize, y as usize))
+    } else {
+        let count = comp_cfg.get_u64("package.pin-count")? as usize / 4;
+        Ok((count, count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pad_names() -> Result<()> {
+        let comp_cfg = Config::new();
+        assert_eq!(super::pad_names(&comp_cfg, 2)?, vec!["1", "2"]);
+
+        let comp_cfg = Config::from_yaml(
+            r"
+        pinout:
+          XTAL: [3, 1]
+          GND: [10, 2]
+        ",
+        )?;
+        assert_eq!(super::pad_names(&comp_cfg, 4)?, vec!["1", "2", "3", "10"]);
+        assert!(super::pad_names(&comp_cfg, 2).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7351B, TwoPin};
+
+use super::{PackageHandler, PackageType};
+
+pub struct MoldedPackage {
+    polarized: bool,
+}
+
+impl MoldedPackage {
+    pub fn new() -> Self {
+        MoldedPackage { polarized: false }
+    }
+
+    /// Builds a `MoldedPackage` with modified default polarity.
+    pub fn polarized(mut self, polarized: bool) -> Self {
+        self.polarized = polarized;
+        self
+    }
+}
+
+impl PackageHandler for MoldedPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw molded pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let lead_span = comp_cfg.get_range("package.lead-span")?;
+        let lead_len = comp_cfg.get_range("package.lead-length")?;
+        let lead_width = comp_cfg.get_range("package.lead-width")?;
+        let polarized = comp_cfg
+            .get_bool("package.polarized")
+            .unwrap_or(self.polarized);
+
+        let pad_props = Ipc7351B::new(PackageType::Molded)
+            .lead_span(lead_span)
+            .lead_len(lead_len)
+            .lead_width(lead_width)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let two_pin = TwoPin::default()
+            .pad_properties(pad_props)
+            .assembly(true)
+            .courtyard(true)
+            .cathode(polarized)
+            .body(body_size_x.nom(), body_size_y.nom());
+
+        let mut drawing = Drawing::new();
+        two_pin.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw molded model");
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_size_x.nom() / 2.0, -body_size_y.nom() / 2.0, 0.0)
+                .dimensions(body_size_x.nom(), body_size_y.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,58 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Size};
+use crate::error::*;
+use crate::pattern::{Ipc7251, PadProperties, RoundPad};
+
+use super::PackageHandler;
+
+pub struct MountingHolePackage {}
+
+impl MountingHolePackage {
+    pub fn new() -> Self {
+        MountingHolePackage {}
+    }
+}
+
+impl PackageHandler for MountingHolePackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw mounting hole pattern");
+
+        let hole = comp_cfg.get_f64("package.hole-diameter")?;
+        let plated = comp_cfg.get_bool("package.plated").unwrap_or(true);
+        let diameter = match comp_cfg.get_f64("package.pad-diameter") {
+            Ok(diameter) => diameter,
+            Err(_) if plated => {
+                let ratio = lib_cfg.get_f64("pattern.ratio.pad-to-hole").unwrap();
+                let min_ring = lib_cfg.get_f64("pattern.minimum.ring-width").unwrap();
+                (hole * ratio).max(hole + 2.0 * min_ring)
+            }
+            Err(_) => hole,
+        };
+
+        let mut pad_props = PadProperties::default();
+        pad_props.size = Size::new(diameter, diameter);
+        pad_props.hole = Some(hole);
+        pad_props.courtyard = Ipc7251::new().settings(lib_cfg).courtyard();
+
+        let mut round_pad = RoundPad::default().pad_properties(pad_props);
+        if !plated {
+            round_pad = round_pad.non_plated();
+        }
+        if let Ok(via_count) = comp_cfg.get_u64("package.via-count") {
+            let via_hole = comp_cfg.get_f64("package.via-diameter")?;
+            round_pad = round_pad.vias(via_count as usize, via_hole);
+        }
+        if let Ok(keepout) = comp_cfg.get_f64("package.keepout-diameter") {
+            round_pad = round_pad.keepout(keepout);
+        }
+
+        let mut drawing = Drawing::new();
+        round_pad.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw mounting hole model");
+        Ok(Drawing::new())
+    }
+}
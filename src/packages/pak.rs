@@ -0,0 +1,95 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7351B, MultiRow};
+use crate::pinout::Pinout;
+
+use super::{PackageHandler, PackageType};
+
+pub struct PakPackage {}
+
+impl PakPackage {
+    pub fn new() -> Self {
+        PakPackage {}
+    }
+}
+
+impl PackageHandler for PakPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw PAK pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let lead_span = comp_cfg.get_range("package.lead-span")?;
+        let lead_len = comp_cfg.get_range("package.lead-length")?;
+        let lead_width = comp_cfg.get_range("package.lead-width")?;
+        let tab_len = comp_cfg
+            .get_range("package.tab-length")
+            .unwrap_or_else(|_| lead_len.clone());
+        let tab_width = comp_cfg.get_range("package.tab-width")?;
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let pin_count = comp_cfg.get_u64("package.pin-count")? as usize;
+
+        let pinout = Pinout::from_config(comp_cfg)?;
+        let depopulated = match comp_cfg.get_element("package.depopulated") {
+            Ok(value) => pinout.parse_number(value)?,
+            Err(_) => Vec::new(),
+        };
+        let names: Vec<String> = (1..=pin_count)
+            .map(|i| i.to_string())
+            .map(|name| {
+                if depopulated.contains(&name) {
+                    String::new()
+                } else {
+                    name
+                }
+            })
+            .collect();
+        let tab_name = match pinout.get_first("TAB") {
+            Some(pin) => pin.number.clone(),
+            None => (pin_count + 1).to_string(),
+        };
+
+        let pad_props = Ipc7351B::new(PackageType::GullWing)
+            .lead_span(lead_span.clone())
+            .lead_len(lead_len)
+            .lead_width(lead_width)
+            .pitch(pitch)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+        let tab_props = Ipc7351B::new(PackageType::GullWing)
+            .lead_span(lead_span)
+            .lead_len(tab_len)
+            .lead_width(tab_width)
+            .settings(lib_cfg)
+            .calc();
+
+        let multi_row = MultiRow::default()
+            .pad_properties(pad_props)
+            .pitch(pitch)
+            .row_len(pin_count)
+            .names(names)
+            .tab(tab_props, &tab_name)
+            .body(body_size_x.nom(), body_size_y.nom());
+
+        let mut drawing = Drawing::new();
+        multi_row.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw PAK model");
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_size_x.nom() / 2.0, -body_size_y.nom() / 2.0, 0.0)
+                .dimensions(body_size_x.nom(), body_size_y.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7351B, MultiRow};
+
+use super::{PackageHandler, PackageType};
+
+pub struct PlccPackage {}
+
+impl PlccPackage {
+    pub fn new() -> Self {
+        PlccPackage {}
+    }
+}
+
+impl PackageHandler for PlccPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw PLCC pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let lead_span_x = comp_cfg.get_range("package.lead-span-x")?;
+        let lead_span_y = comp_cfg.get_range("package.lead-span-y")?;
+        let lead_len = comp_cfg.get_range("package.lead-length")?;
+        let lead_width = comp_cfg.get_range("package.lead-width")?;
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let (pin_count_x, pin_count_y) = super::pin_counts_xy(comp_cfg)?;
+
+        let pad_props_x = Ipc7351B::new(PackageType::JLead)
+            .lead_span(lead_span_x)
+            .lead_len(lead_len.clone())
+            .lead_width(lead_width.clone())
+            .pitch(pitch)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+        let pad_props_y = Ipc7351B::new(PackageType::JLead)
+            .lead_span(lead_span_y)
+            .lead_len(lead_len)
+            .lead_width(lead_width)
+            .pitch(pitch)
+            .settings(lib_cfg)
+            .calc()
+            .post_proc(comp_cfg, lib_cfg);
+
+        let multi_row = MultiRow::default()
+            .pad_properties(pad_props_x)
+            .pad_properties_y(pad_props_y)
+            .pitch(pitch)
+            .row_len(pin_count_y)
+            .row_len_y(pin_count_x)
+            .pin1_top()
+            .body(body_size_x.nom(), body_size_y.nom());
+
+        let mut drawing = Drawing::new();
+        multi_row.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw PLCC model");
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_size_x.nom() / 2.0, -body_size_y.nom() / 2.0, 0.0)
+                .dimensions(body_size_x.nom(), body_size_y.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing};
+use crate::error::*;
+use crate::pattern::{Ipc7251, TwoPin};
+
+use super::PackageHandler;
+
+pub struct RadialPackage {}
+
+impl RadialPackage {
+    pub fn new() -> Self {
+        RadialPackage {}
+    }
+}
+
+impl PackageHandler for RadialPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw radial pattern");
+
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+        let lead_diameter = comp_cfg.get_range("package.lead-diameter")?;
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let polarized = comp_cfg.get_bool("package.polarized").unwrap_or(false);
+
+        let mut pad_props = Ipc7251::new()
+            .lead_width(lead_diameter)
+            .settings(lib_cfg)
+            .calc();
+        pad_props.distance = pitch;
+
+        let two_pin = TwoPin::default()
+            .pad_properties(pad_props)
+            .courtyard(true)
+            .polarized(polarized)
+            .body_diameter(body_diameter.nom());
+
+        let mut drawing = Drawing::new();
+        two_pin.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw radial model");
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+        // Height is optional and defaults to the body diameter
+        let body_size_z = super::body_size_z(comp_cfg).unwrap_or_else(|_| body_diameter.clone());
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_diameter.nom() / 2.0, -body_diameter.nom() / 2.0, 0.0)
+                .dimensions(body_diameter.nom(), body_diameter.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
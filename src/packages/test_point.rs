@@ -0,0 +1,54 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Size};
+use crate::error::*;
+use crate::pattern::{Ipc7251, Ipc7351B, PadProperties, RoundPad};
+
+use super::{PackageHandler, PackageType};
+
+pub struct TestPointPackage {}
+
+impl TestPointPackage {
+    pub fn new() -> Self {
+        TestPointPackage {}
+    }
+}
+
+impl PackageHandler for TestPointPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw test point pattern");
+
+        // Through-hole loop is defined by its wire diameter, surface mount test point by its pad
+        let pad_props = match comp_cfg.get_range("package.lead-diameter") {
+            Ok(lead_diameter) => {
+                let mut pad_props = Ipc7251::new()
+                    .lead_width(lead_diameter)
+                    .settings(lib_cfg)
+                    .calc();
+                if let Ok(diameter) = comp_cfg.get_f64("package.pad-diameter") {
+                    pad_props.size = Size::new(diameter, diameter);
+                }
+                pad_props
+            }
+            Err(_) => {
+                let diameter = comp_cfg.get_f64("package.pad-diameter")?;
+                let mut pad_props = PadProperties::default();
+                pad_props.size = Size::new(diameter, diameter);
+                pad_props.courtyard = Ipc7351B::new(PackageType::Unknown)
+                    .settings(lib_cfg)
+                    .courtyard();
+                pad_props
+            }
+        };
+
+        let round_pad = RoundPad::default().pad_properties(pad_props);
+
+        let mut drawing = Drawing::new();
+        round_pad.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, _comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw test point model");
+        Ok(Drawing::new())
+    }
+}
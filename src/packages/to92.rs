@@ -0,0 +1,89 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing, Point};
+use crate::error::*;
+use crate::pattern::{Ipc7251, ToBody, TransistorOutline};
+
+use super::PackageHandler;
+
+pub struct To92Package {}
+
+impl To92Package {
+    pub fn new() -> Self {
+        To92Package {}
+    }
+}
+
+impl PackageHandler for To92Package {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw TO-92 pattern");
+
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let lead_width = comp_cfg.get_range("package.lead-width")?;
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let pin_count = comp_cfg.get_u64("package.pin-count").unwrap_or(3) as usize;
+        let lead_forming = comp_cfg.get_str("package.lead-forming").unwrap_or("inline");
+
+        let mut ipc = Ipc7251::new().lead_width(lead_width);
+        if let Ok(lead_height) = comp_cfg.get_range("package.lead-height") {
+            ipc = ipc.lead_height(lead_height);
+        }
+        let mut pad_props = ipc.settings(lib_cfg).calc();
+
+        let leads: Vec<Point> = match lead_forming {
+            // Outer leads are two pitches apart, the middle lead is bent to the back side by one pitch
+            "triangular" => {
+                ensure!(
+                    pin_count == 3,
+                    QedaError::UnsupportedPinCount("to92", pin_count)
+                );
+                vec![
+                    Point::new(-pitch, 0.0),
+                    Point::new(0.0, -pitch),
+                    Point::new(pitch, 0.0),
+                ]
+            }
+            _ => {
+                // Inline pads are narrowed to keep clearance between them
+                let clearance = lib_cfg.get_f64("pattern.clearance.pad-to-pad").unwrap();
+                if pad_props.size.x > pitch - clearance {
+                    pad_props.size.x = pitch - clearance;
+                }
+                let x0 = -(pin_count as f64 - 1.0) * pitch / 2.0;
+                (0..pin_count)
+                    .map(|i| Point::new(x0 + i as f64 * pitch, 0.0))
+                    .collect()
+            }
+        };
+
+        // Flat side faces the front (bottom) side
+        let radius = body_size_x.nom() / 2.0;
+        let transistor_outline = TransistorOutline::default()
+            .pad_properties(pad_props)
+            .leads(leads)
+            .body(ToBody::Flat {
+                radius,
+                flat: body_size_y.nom() - radius,
+            });
+
+        let mut drawing = Drawing::new();
+        transistor_outline.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw TO-92 model");
+        let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+        let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let radius = body_size_x.nom() / 2.0;
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(Box3D::new().origin(-radius, -radius, 0.0).dimensions(
+            body_size_x.nom(),
+            body_size_y.nom(),
+            body_size_z.max(),
+        ));
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,75 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing, Point, Size};
+use crate::error::*;
+use crate::pattern::{Ipc7251, ToBody, TransistorOutline};
+
+use super::PackageHandler;
+
+pub struct ToCanPackage {}
+
+impl ToCanPackage {
+    pub fn new() -> Self {
+        ToCanPackage {}
+    }
+}
+
+impl PackageHandler for ToCanPackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw TO can pattern");
+
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+        let lead_circle = comp_cfg.get_f64("package.lead-circle-diameter")?;
+        let lead_diameter = comp_cfg.get_range("package.lead-diameter")?;
+        let tab_width = comp_cfg.get_range("package.tab-width")?;
+        let tab_length = comp_cfg.get_range("package.tab-length")?;
+        let pin_count = comp_cfg.get_u64("package.pin-count")? as usize;
+        // Three and four leads are spaced by 90°, more leads are spread evenly
+        let step = match comp_cfg.get_f64("package.lead-angle") {
+            Ok(angle) => angle,
+            Err(_) if pin_count <= 4 => 90.0,
+            Err(_) => 360.0 / pin_count as f64,
+        };
+
+        let pad_props = Ipc7251::new()
+            .lead_width(lead_diameter)
+            .settings(lib_cfg)
+            .calc();
+
+        // Pin 1 is at the left, numbering runs counter-clockwise (top view)
+        let radius = lead_circle / 2.0;
+        let leads = (0..pin_count)
+            .map(|i| {
+                let angle = (180.0 - i as f64 * step).to_radians();
+                Point::new(radius * angle.cos(), radius * angle.sin())
+            })
+            .collect();
+
+        // Tab is next to pin 1
+        let transistor_outline = TransistorOutline::default()
+            .pad_properties(pad_props)
+            .leads(leads)
+            .body(ToBody::Can {
+                radius: body_diameter.nom() / 2.0,
+                angle: 180.0 + step / 2.0,
+                tab: Size::new(tab_width.nom(), tab_length.nom()),
+            });
+
+        let mut drawing = Drawing::new();
+        transistor_outline.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw TO can model");
+        let body_diameter = comp_cfg.get_range("package.body-diameter")?;
+        let body_size_z = super::body_size_z(comp_cfg)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(
+            Box3D::new()
+                .origin(-body_diameter.nom() / 2.0, -body_diameter.nom() / 2.0, 0.0)
+                .dimensions(body_diameter.nom(), body_diameter.nom(), body_size_z.max()),
+        );
+        Ok(drawing)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::config::Config;
+use crate::drawing::{Box3D, Drawing, Point, Rect};
+use crate::error::*;
+use crate::pattern::{Ipc7251, PadProperties, ToBody, TransistorOutline};
+
+use super::PackageHandler;
+
+pub struct ToFlangePackage {}
+
+impl ToFlangePackage {
+    pub fn new() -> Self {
+        ToFlangePackage {}
+    }
+}
+
+impl PackageHandler for ToFlangePackage {
+    fn draw_pattern(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw TO flange pattern");
+
+        let pitch = comp_cfg.get_f64("package.pitch")?;
+        let pin_count = comp_cfg.get_u64("package.pin-count").unwrap_or(3) as usize;
+        let pad_props = pad_properties(comp_cfg, lib_cfg)?;
+
+        let x0 = -(pin_count as f64 - 1.0) * pitch / 2.0;
+        let leads = (0..pin_count)
+            .map(|i| Point::new(x0 + i as f64 * pitch, 0.0))
+            .collect();
+
+        let (rect, _) = body(comp_cfg, &pad_props)?;
+        let mut transistor_outline = TransistorOutline::default().leads(leads);
+        if is_horizontal(comp_cfg) {
+            transistor_outline = transistor_outline.body(ToBody::Rect {
+                rect: rect.clone(),
+                tab: None,
+            });
+            // Tab with a mounting hole is at the far end of the body
+            if let Ok(hole) = comp_cfg.get_f64("package.hole-diameter") {
+                let hole_offset = comp_cfg.get_f64("package.hole-offset")?;
+                transistor_outline = transistor_outline.hole(0.0, rect.p.0.y + hole_offset, hole);
+            }
+        } else {
+            transistor_outline = transistor_outline.body(ToBody::Rect {
+                rect,
+                tab: comp_cfg
+                    .get_range("package.tab-thickness")
+                    .ok()
+                    .map(|tab| tab.nom()),
+            });
+        }
+        let transistor_outline = transistor_outline.pad_properties(pad_props);
+
+        let mut drawing = Drawing::new();
+        transistor_outline.draw(&mut drawing, lib_cfg);
+        Ok(drawing)
+    }
+
+    fn draw_model(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Drawing> {
+        debug!("draw TO flange model");
+        let pad_props = pad_properties(comp_cfg, lib_cfg)?;
+        let (rect, height) = body(comp_cfg, &pad_props)?;
+
+        let mut drawing = Drawing::new();
+        drawing.add_box3d(Box3D::new().origin(rect.p.0.x, rect.p.0.y, 0.0).dimensions(
+            rect.p.1.x - rect.p.0.x,
+            rect.p.1.y - rect.p.0.y,
+            height,
+        ));
+        Ok(drawing)
+    }
+}
+
+// Check whether the body lies on the board behind the bent leads
+fn is_horizontal(comp_cfg: &Config) -> bool {
+    comp_cfg
+        .get_str("package.orientation")
+        .unwrap_or("vertical")
+        == "horizontal"
+}
+
+// Calculate pad properties of the leads
+fn pad_properties(comp_cfg: &Config, lib_cfg: &Config) -> Result<PadProperties> {
+    let lead_width = comp_cfg.get_range("package.lead-width")?;
+    let mut ipc = Ipc7251::new().lead_width(lead_width);
+    if let Ok(lead_height) = comp_cfg.get_range("package.lead-height") {
+        ipc = ipc.lead_height(lead_height);
+    }
+    Ok(ipc.settings(lib_cfg).calc())
+}
+
+// Get the body outline on the board and the body height above it
+fn body(comp_cfg: &Config, pad_props: &PadProperties) -> Result<(Rect, f64)> {
+    let body_size_x = comp_cfg.get_range("package.body-size-x")?;
+    let body_size_y = comp_cfg.get_range("package.body-size-y")?;
+    let body_size_z = super::body_size_z(comp_cfg)?;
+    let width = body_size_x.nom();
+    if is_horizontal(comp_cfg) {
+        // Body lies behind the bent leads
+        let offset = comp_cfg
+            .get_f64("package.body-offset")
+            .unwrap_or(pad_props.size.x);
+        let y0 = -offset - body_size_y.nom();
+        Ok((
+            Rect::new(-width / 2.0, y0, width / 2.0, -offset),
+            body_size_z.max(),
+        ))
+    } else {
+        // Standing body is seen from the top, the tab is at the back side
+        let thickness = body_size_z.nom();
+        Ok((
+            Rect::new(-width / 2.0, -thickness / 2.0, width / 2.0, thickness / 2.0),
+            body_size_y.max(),
+        ))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Layer, Line, Rect};
+
+/// Draws a body outline on the assembly layer.
+pub fn draw_body(drawing: &mut Drawing, body: &Rect, lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+
+    let rect = body
+        .clone()
+        .line_width(line_width)
+        .layer(Layer::ASSEMBLY_TOP);
+
+    let lines: Vec<Line> = rect.to_lines();
+    drawing.add_lines(lines);
+}
+
+/// Draws a body outline with chamfered left corners on the assembly layer.
+pub fn draw_chamfered_body(drawing: &mut Drawing, body: &Rect, chamfer: f64, lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+
+    let polyline = body
+        .clone()
+        .line_width(line_width)
+        .layer(Layer::ASSEMBLY_TOP)
+        .to_chamfered_left(chamfer);
+
+    drawing.add_lines(polyline.to_lines());
+}
+
+/// Draws a cathode band across the left part of the body.
+pub fn draw_cathode(drawing: &mut Drawing, body: &Rect, lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+
+    let x = body.p.0.x + (body.p.1.x - body.p.0.x) / 4.0;
+    drawing.add_line(
+        Line::new(x, body.p.0.y, x, body.p.1.y)
+            .width(line_width)
+            .layer(Layer::ASSEMBLY_TOP),
+    );
+}
@@ -33,6 +33,11 @@ impl Ipc7351B {
         }
     }
 
+    /// Returns the courtyard excess for the density level applied by `settings`.
+    pub fn courtyard(&self) -> f64 {
+        self.courtyard
+    }
+
     /// Sets a lead span, i.e. a distance from the one lead edge to the opposite lead edge.
     pub fn lead_span(mut self, span: Range) -> Self {
         self.lead_span = span;
@@ -57,8 +62,20 @@ impl Ipc7351B {
         self
     }
 
+    /// Sets the lead pitch, i.e. a distance between adjacent lead centers.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = Some(pitch);
+        self
+    }
+
     /// Calculates pad parameters.
     pub fn calc(self) -> PadProperties {
+        match self.package_type {
+            PackageType::Bga => return self.calc_ball(),
+            PackageType::Lga => return self.calc_land(),
+            _ => (),
+        }
+
         let span_tol = self.lead_span.tol();
         let len_tol = self.lead_len.tol();
         let width_tol = self.lead_width.tol();
@@ -122,7 +139,7 @@ impl Ipc7351B {
         // Pad height should not violate clearance rules
         if let Some(pitch) = self.pitch {
             if pad_height > (pitch - self.clearance) {
-                pad_height = pitch - self.clearance;
+                pad_height = Self::round_size(pitch - self.clearance);
             }
         }
 
@@ -131,6 +148,7 @@ impl Ipc7351B {
             distance: pad_distance,
             courtyard: self.courtyard,
             lead_span: self.lead_span.nom(),
+            ..PadProperties::default()
         }
     }
 
@@ -142,6 +160,40 @@ impl Ipc7351B {
         self.density_level(lib_cfg.get_str("pattern.density-level").unwrap())
     }
 
+    // Calculate land for a collapsing ball, lead width is treated as a ball diameter
+    fn calc_ball(self) -> PadProperties {
+        let ball = self.lead_width.nom();
+        let reduction = if ball >= 0.55 {
+            0.25
+        } else if ball >= 0.25 {
+            0.2
+        } else {
+            0.15
+        };
+        let land = Self::round_size(ball * (1.0 - reduction));
+        PadProperties {
+            size: Size::new(land, land),
+            distance: 0.0,
+            courtyard: self.courtyard,
+            lead_span: 0.0,
+            ..PadProperties::default()
+        }
+    }
+
+    // Calculate land for a non-collapsing terminal, land is equal to the nominal terminal size
+    fn calc_land(self) -> PadProperties {
+        PadProperties {
+            size: Size::new(
+                Self::round_size(self.lead_width.nom()),
+                Self::round_size(self.lead_len.nom()),
+            ),
+            distance: 0.0,
+            courtyard: self.courtyard,
+            lead_span: 0.0,
+            ..PadProperties::default()
+        }
+    }
+
     // Set goals according to the density level
     fn density_level(mut self, density_level: &str) -> Self {
         let i = match density_level {
@@ -150,6 +202,18 @@ impl Ipc7351B {
             _ => 1, // "N", "nominal"
         };
         let (toe, heel, side, courtyard) = match self.package_type {
+            PackageType::Bga | PackageType::Lga => (
+                0.0,                    // Toe
+                0.0,                    // Heel
+                0.0,                    // Side
+                vec![2.0, 1.0, 0.5][i], // Coutyard
+            ),
+            PackageType::Cae => (
+                vec![0.7, 0.5, 0.3][i],   // Toe
+                vec![0.0, -0.1, -0.2][i], // Heel
+                vec![0.5, 0.4, 0.3][i],   // Side
+                vec![1.0, 0.5, 0.25][i],  // Coutyard
+            ),
             PackageType::Chip => {
                 let len = self.lead_span.nom(); //(self.lead_span.0 + self.lead_span.1) / 2.0;
                 if len <= 0.5 {
@@ -210,6 +274,56 @@ impl Ipc7351B {
                     )
                 }
             }
+            PackageType::ChipArray => (
+                vec![0.55, 0.45, 0.35][i],   // Toe
+                vec![-0.05, -0.07, -0.1][i], // Heel
+                vec![-0.05, -0.07, -0.1][i], // Side
+                vec![0.5, 0.25, 0.12][i],    // Coutyard
+            ),
+            PackageType::GullWing => {
+                if matches!(self.pitch, Some(pitch) if pitch <= 0.625) {
+                    // Pitch 0.625 mm & Less
+                    (
+                        vec![0.55, 0.35, 0.15][i],   // Toe
+                        vec![0.45, 0.35, 0.25][i],   // Heel
+                        vec![0.01, -0.02, -0.04][i], // Side
+                        vec![0.5, 0.25, 0.12][i],    // Coutyard
+                    )
+                } else {
+                    // Pitch greater than 0.625 mm
+                    (
+                        vec![0.55, 0.35, 0.15][i], // Toe
+                        vec![0.45, 0.35, 0.25][i], // Heel
+                        vec![0.05, 0.03, 0.01][i], // Side
+                        vec![0.5, 0.25, 0.12][i],  // Coutyard
+                    )
+                }
+            }
+            // J-leads bend under the body, so the heel takes the larger goal
+            PackageType::JLead => (
+                vec![0.1, 0.0, -0.1][i],   // Toe
+                vec![0.55, 0.35, 0.15][i], // Heel
+                vec![0.05, 0.03, 0.01][i], // Side
+                vec![0.5, 0.25, 0.12][i],  // Coutyard
+            ),
+            PackageType::Melf => (
+                vec![0.6, 0.4, 0.2][i],   // Toe
+                vec![0.2, 0.1, 0.02][i],  // Heel
+                vec![0.1, 0.05, 0.01][i], // Side
+                vec![0.5, 0.25, 0.12][i], // Coutyard
+            ),
+            PackageType::Molded => (
+                vec![0.25, 0.15, 0.07][i],  // Toe
+                vec![0.8, 0.5, 0.2][i],     // Heel
+                vec![0.01, -0.05, -0.1][i], // Side
+                vec![0.5, 0.25, 0.12][i],   // Coutyard
+            ),
+            PackageType::Qfn | PackageType::Son => (
+                vec![0.4, 0.3, 0.2][i],       // Toe
+                vec![0.0, 0.0, 0.0][i],       // Heel
+                vec![-0.04, -0.04, -0.04][i], // Side
+                vec![0.5, 0.25, 0.1][i],      // Coutyard
+            ),
             _ => (
                 vec![0.55, 0.35, 0.15][i], // Toe
                 vec![0.45, 0.35, 0.25][i], // Heel
@@ -224,19 +338,111 @@ impl Ipc7351B {
         self
     }
 
-    // Round off placement values
-    fn round_place(value: f64) -> f64 {
+    /// Rounds off a placement value to the 0.02 mm grid.
+    pub fn round_place(value: f64) -> f64 {
         let factor = 0.02;
         (value / factor).round() * factor
     }
 
-    // Round off size values
-    fn round_size(value: f64) -> f64 {
+    /// Rounds off a size value to the 0.01 mm grid.
+    pub fn round_size(value: f64) -> f64 {
         let factor = 0.01;
         (value / factor).round() * factor
     }
 }
 
+/// Through-hole land calculator according to IPC-7251.
+#[derive(Debug, Default)]
+pub struct Ipc7251 {
+    lead_width: Range,
+    lead_height: Option<Range>,
+
+    hole_over_lead: f64,
+    pad_over_hole: f64,
+    courtyard: f64,
+
+    lead_to_hole: f64,
+    min_hole: f64,
+    min_ring: f64,
+    pad_to_hole: f64,
+}
+
+impl Ipc7251 {
+    /// Creates an empty Ipc7251.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calculates pad and hole parameters.
+    pub fn calc(self) -> PadProperties {
+        // Rectangular lead is fitted by its diagonal
+        let lead = match &self.lead_height {
+            Some(height) => self.lead_width.max().hypot(height.max()),
+            None => self.lead_width.max(),
+        };
+        let hole = (lead + self.hole_over_lead)
+            .max(lead + 2.0 * self.lead_to_hole)
+            .max(self.min_hole);
+        let hole = Self::round_up(hole);
+        let pad = (hole + self.pad_over_hole)
+            .max(hole * self.pad_to_hole)
+            .max(hole + 2.0 * self.min_ring);
+        let pad = Self::round_up(pad);
+
+        PadProperties {
+            size: Size::new(pad, pad),
+            hole: Some(hole),
+            courtyard: self.courtyard,
+            ..PadProperties::default()
+        }
+    }
+
+    /// Returns the courtyard excess for the density level applied by `settings`.
+    pub fn courtyard(&self) -> f64 {
+        self.courtyard
+    }
+
+    /// Sets the lead width or diameter.
+    pub fn lead_width(mut self, width: Range) -> Self {
+        self.lead_width = width;
+        self
+    }
+
+    /// Sets the lead height for leads of rectangular cross-section.
+    pub fn lead_height(mut self, height: Range) -> Self {
+        self.lead_height = Some(height);
+        self
+    }
+
+    /// Gets settings from a config and applies them.
+    pub fn settings(mut self, lib_cfg: &Config) -> Self {
+        self.lead_to_hole = lib_cfg.get_f64("pattern.clearance.lead-to-hole").unwrap();
+        self.min_hole = lib_cfg.get_f64("pattern.minimum.hole-diameter").unwrap();
+        self.min_ring = lib_cfg.get_f64("pattern.minimum.ring-width").unwrap();
+        self.pad_to_hole = lib_cfg.get_f64("pattern.ratio.pad-to-hole").unwrap();
+        self.density_level(lib_cfg.get_str("pattern.density-level").unwrap())
+    }
+
+    // Set goals according to the density level, IPC-7251 levels A, B and C
+    fn density_level(mut self, density_level: &str) -> Self {
+        let i = match density_level {
+            "M" | "m" | "most" | "A" | "a" => 0,
+            "L" | "l" | "least" | "C" | "c" => 2,
+            _ => 1, // "N", "nominal", "B"
+        };
+        self.hole_over_lead = vec![0.25, 0.2, 0.15][i];
+        self.pad_over_hole = vec![0.6, 0.5, 0.4][i];
+        self.courtyard = vec![0.5, 0.25, 0.12][i];
+        self
+    }
+
+    // Round up to the 0.01 mm grid
+    fn round_up(value: f64) -> f64 {
+        let factor = 0.01;
+        ((value / factor) - 1e-6).ceil() * factor
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,4 +462,60 @@ mod tests {
         assert_eq!(pad_props.size.y, 0.6);
         assert_eq!(pad_props.courtyard, 0.25);
     }
+
+    #[test]
+    fn gull_wing_fine_pitch() {
+        let pad_props = Ipc7351B::new(PackageType::GullWing)
+            .lead_span(Range(6.2, 6.6))
+            .lead_width(Range(0.15, 0.25))
+            .lead_len(Range(0.45, 0.75))
+            .pitch(0.4)
+            .settings(&load_config!("../qeda.yml"))
+            .calc();
+
+        // Pad height is limited by pitch and pad-to-pad clearance
+        assert_eq!(pad_props.size.y, 0.2);
+        assert_eq!(pad_props.courtyard, 0.25);
+    }
+
+    #[test]
+    fn chip_array() {
+        let pad_props = Ipc7351B::new(PackageType::ChipArray)
+            .lead_span(Range(1.5, 1.7))
+            .lead_width(Range(0.55, 0.75))
+            .lead_len(Range(0.2, 0.4))
+            .pitch(0.8)
+            .settings(&load_config!("../qeda.yml"))
+            .calc();
+
+        // Pad height is limited by pitch and pad-to-pad clearance
+        assert_eq!(pad_props.size.y, 0.6);
+        assert_eq!(pad_props.courtyard, 0.25);
+    }
+
+    #[test]
+    fn lga() {
+        let pad_props = Ipc7351B::new(PackageType::Lga)
+            .lead_width(Range(0.45, 0.55))
+            .lead_len(Range(0.75, 0.85))
+            .settings(&load_config!("../qeda.yml"))
+            .calc();
+
+        // Land is equal to the nominal terminal size
+        assert_eq!(pad_props.size.x, 0.5);
+        assert_eq!(pad_props.size.y, 0.8);
+        assert_eq!(pad_props.courtyard, 1.0);
+    }
+
+    #[test]
+    fn tht() {
+        let pad_props = Ipc7251::new()
+            .lead_width(Range(0.36, 0.56))
+            .settings(&load_config!("../qeda.yml"))
+            .calc();
+
+        assert_eq!(pad_props.hole, Some(0.76));
+        assert_eq!(pad_props.size.x, 1.26);
+        assert_eq!(pad_props.courtyard, 0.25);
+    }
 }
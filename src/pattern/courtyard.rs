@@ -0,0 +1,31 @@
+use crate::config::Config;
+use crate::drawing::{Drawing, Layer, Line, Pad, Rect};
+
+pub fn draw(drawing: &mut Drawing, body: &Rect, pads: &[Pad], courtyard: f64, lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.courtyard").unwrap();
+
+    let mut x0 = body.p.0.x;
+    let mut y0 = body.p.0.y;
+    let mut x1 = body.p.1.x;
+    let mut y1 = body.p.1.y;
+    for pad in pads {
+        x0 = x0.min(pad.origin.x - pad.size.x / 2.0);
+        y0 = y0.min(pad.origin.y - pad.size.y / 2.0);
+        x1 = x1.max(pad.origin.x + pad.size.x / 2.0);
+        y1 = y1.max(pad.origin.y + pad.size.y / 2.0);
+    }
+
+    // Round off to the 0.01 mm grid
+    let round = |value: f64| (value / 0.01).round() * 0.01;
+    let rect = Rect::new(
+        round(x0 - courtyard),
+        round(y0 - courtyard),
+        round(x1 + courtyard),
+        round(y1 + courtyard),
+    )
+    .line_width(line_width)
+    .layer(Layer::COURTYARD_TOP);
+
+    let lines: Vec<Line> = rect.to_lines();
+    drawing.add_lines(lines);
+}
@@ -0,0 +1,136 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+use super::{assembly, courtyard, mask, silkscreen, Ipc7351B};
+
+#[derive(Debug, Default)]
+pub struct Custom {
+    pads: Vec<Pad>,
+    body: Option<Rect>,
+    silkscreen: Vec<Polyline>,
+    assembly: Vec<Polyline>,
+    courtyard: Vec<Polyline>,
+    courtyard_excess: f64,
+}
+
+impl Custom {
+    /// Builds a `Custom` with modified assembly outline.
+    pub fn assembly(mut self, polylines: Vec<Polyline>) -> Self {
+        self.assembly = polylines;
+        self
+    }
+
+    /// Builds a `Custom` with modified body, it is used when outlines are not specified.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body = Some(Rect::new(
+            -width / 2.0,
+            -height / 2.0,
+            width / 2.0,
+            height / 2.0,
+        ));
+        self
+    }
+
+    /// Builds a `Custom` with modified courtyard outline.
+    ///
+    /// Without it, the courtyard is a rectangle around the body and pads.
+    pub fn courtyard(mut self, polylines: Vec<Polyline>) -> Self {
+        self.courtyard = polylines;
+        self
+    }
+
+    /// Builds a `Custom` with modified courtyard excess over the body and pads.
+    pub fn courtyard_excess(mut self, excess: f64) -> Self {
+        self.courtyard_excess = excess;
+        self
+    }
+
+    /// Draws custom pattern.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        // Sizes are rounded off the same way as calculated pads, placement is kept as given
+        let size = Ipc7351B::round_size;
+        let mut pads: Vec<Pad> = self
+            .pads
+            .iter()
+            .cloned()
+            .map(|mut pad| {
+                pad.size = Size::new(size(pad.size.x), size(pad.size.y));
+                pad.hole = pad.hole.map(|hole| Size::new(size(hole.x), size(hole.y)));
+                pad
+            })
+            .collect();
+        mask::calc(&mut pads, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        if !self.silkscreen.is_empty() {
+            let lines = self
+                .silkscreen
+                .iter()
+                .flat_map(|polyline| {
+                    polyline
+                        .clone()
+                        .line_width(line_width)
+                        .layer(Layer::SILKSCREEN_TOP)
+                        .to_lines()
+                })
+                .collect();
+            silkscreen::draw_clipped(drawing, lines, &pads, lib_cfg);
+        } else if let Some(body) = &self.body {
+            silkscreen::draw_body(drawing, body, &pads, lib_cfg);
+        }
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+        if !self.assembly.is_empty() {
+            for polyline in &self.assembly {
+                drawing.add_lines(
+                    polyline
+                        .clone()
+                        .line_width(line_width)
+                        .layer(Layer::ASSEMBLY_TOP)
+                        .to_lines(),
+                );
+            }
+        } else if let Some(body) = &self.body {
+            assembly::draw_body(drawing, body, lib_cfg);
+        }
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.courtyard").unwrap();
+        if !self.courtyard.is_empty() {
+            for polyline in &self.courtyard {
+                drawing.add_lines(
+                    polyline
+                        .clone()
+                        .line_width(line_width)
+                        .layer(Layer::COURTYARD_TOP)
+                        .to_lines(),
+                );
+            }
+        } else {
+            courtyard::draw(
+                drawing,
+                &self.body.clone().unwrap_or_default(),
+                &pads,
+                self.courtyard_excess,
+                lib_cfg,
+            );
+        }
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `Custom` with modified pads.
+    pub fn pads(mut self, pads: Vec<Pad>) -> Self {
+        self.pads = pads;
+        self
+    }
+
+    /// Builds a `Custom` with modified silkscreen outline.
+    ///
+    /// Parts of the outline violating pad-to-silkscreen clearance are cut off.
+    pub fn silkscreen(mut self, polylines: Vec<Polyline>) -> Self {
+        self.silkscreen = polylines;
+        self
+    }
+}
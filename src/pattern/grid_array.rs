@@ -0,0 +1,142 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::pinout::Pinout;
+
+use super::{courtyard, mask, silkscreen, PadProperties};
+
+/// Land override: name, origin and size, unspecified ones are taken from the grid.
+pub type Land = (String, Option<Point>, Option<Size>);
+
+#[derive(Debug, Default)]
+pub struct GridArray {
+    pad_props: PadProperties,
+    pitch: Size,
+    rows: usize,
+    columns: usize,
+    depopulated: Vec<String>,
+    lands: Vec<Land>,
+    shape: PadShape,
+    body: Rect,
+}
+
+impl GridArray {
+    /// Builds a `GridArray` with modified body.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self
+    }
+
+    /// Builds a `GridArray` with modified number of columns.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Builds a `GridArray` with missing pads.
+    pub fn depopulated(mut self, names: Vec<String>) -> Self {
+        self.depopulated = names;
+        self
+    }
+
+    /// Draws grid array pattern.
+    ///
+    /// Pad names consist of a JEDEC row letter and a column number, `A1` is at the top left corner.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let pinout = Pinout::new();
+        let pad = match self.pad_props.hole {
+            Some(hole) => Pad::new("").hole(hole, hole).layers(
+                Layer::COPPER_TOP | Layer::COPPER_BOTTOM | Layer::MASK_TOP | Layer::MASK_BOTTOM,
+            ),
+            None => Pad::new("")
+                .shape(self.shape.clone())
+                .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP),
+        }
+        .size(self.pad_props.size.x, self.pad_props.size.y);
+
+        let x0 = -(self.columns as f64 - 1.0) * self.pitch.x / 2.0;
+        let y0 = -(self.rows as f64 - 1.0) * self.pitch.y / 2.0;
+        let mut pads = Vec::new();
+        for row in 0..self.rows {
+            let row_name = pinout.row_name(row).unwrap_or_default();
+            for col in 0..self.columns {
+                let name = format!("{}{}", row_name, col + 1);
+                if self.depopulated.contains(&name) {
+                    continue;
+                }
+                pads.push(pad.clone().name(&name).origin(
+                    x0 + col as f64 * self.pitch.x,
+                    y0 + row as f64 * self.pitch.y,
+                ));
+            }
+        }
+        // Through-hole pin 1 is marked by a square pad
+        if let Some(first) = pads.first_mut().filter(|pad| !pad.is_smd()) {
+            first.shape = PadShape::Rect;
+        }
+        for (name, origin, size) in &self.lands {
+            let index = match pads.iter().position(|pad| &pad.name == name) {
+                Some(index) => index,
+                // Lands outside of the grid are added, if placed explicitly
+                None if origin.is_some() => {
+                    pads.push(pad.clone().name(name));
+                    pads.len() - 1
+                }
+                None => continue,
+            };
+            if let Some(origin) = origin {
+                pads[index].origin = origin.clone();
+            }
+            if let Some(size) = size {
+                pads[index].size = size.clone();
+            }
+        }
+
+        mask::calc(&mut pads, lib_cfg);
+        silkscreen::draw_body(drawing, &self.body, &pads, lib_cfg);
+        silkscreen::draw_corner_mark(drawing, &self.body, lib_cfg);
+        courtyard::draw(
+            drawing,
+            &self.body,
+            &pads,
+            self.pad_props.courtyard,
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `GridArray` with a modified land or an additional one placed off the grid.
+    pub fn land(mut self, name: &str, origin: Option<Point>, size: Option<Size>) -> Self {
+        self.lands.push((name.to_string(), origin, size));
+        self
+    }
+
+    /// Builds a `GridArray` with modified pad properties.
+    pub fn pad_properties(mut self, pad_props: PadProperties) -> Self {
+        self.pad_props = pad_props;
+        self
+    }
+
+    /// Builds a `GridArray` with modified pitch.
+    pub fn pitch(mut self, x: f64, y: f64) -> Self {
+        self.pitch = Size::new(x, y);
+        self
+    }
+
+    /// Builds a `GridArray` with modified shape of surface mount pads.
+    pub fn shape(mut self, shape: PadShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Builds a `GridArray` with modified number of rows.
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+}
@@ -17,7 +17,12 @@ pub fn calc(pads: &mut [Pad], config: &Config) {
             let p2 = &pads[j];
             let hspace = (p2.origin.x - p1.origin.x).abs() - (p1.size.x + p2.size.x) / 2.0;
             let vspace = (p2.origin.y - p1.origin.y).abs() - (p1.size.y + p2.size.y) / 2.0;
-            let space = hspace.max(vspace);
+            let space = if hspace > 0.0 && vspace > 0.0 {
+                // Diagonal neighbours, e.g. corner pads where X and Y rows meet
+                (hspace * hspace + vspace * vspace).sqrt()
+            } else {
+                hspace.max(vspace)
+            };
 
             // If pads are too near one to another, we need to shrink the mask. Minimum mask is zero (= copper).
             let mut pad_mask = mask;
@@ -36,3 +41,22 @@ pub fn calc(pads: &mut [Pad], config: &Config) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corner() {
+        let lib_cfg = load_config!("../qeda.yml");
+        let mut pads = vec![
+            Pad::new("1").size(1.5, 0.5).origin(-5.0, -4.0),
+            Pad::new("2").size(0.5, 1.5).origin(-3.75, -5.2),
+        ];
+        calc(&mut pads, &lib_cfg);
+
+        // Diagonal space is large enough to keep the default mask
+        assert_eq!(pads[0].mask, 0.05);
+        assert_eq!(pads[1].mask, 0.05);
+    }
+}
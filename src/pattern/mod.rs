@@ -1,18 +1,34 @@
+mod assembly;
 mod calc;
+mod courtyard;
+mod custom;
+mod grid_array;
 mod mask;
+mod multi_row;
+mod paste;
+mod pin_header;
+mod round_pad;
 mod silkscreen;
+mod transistor_outline;
 mod two_pin;
 
 use crate::config::Config;
 use crate::drawing::{Attribute, Drawing, Layer, Size};
 
-pub use calc::Ipc7351B;
+pub use calc::{Ipc7251, Ipc7351B};
+pub use custom::Custom;
+pub use grid_array::{GridArray, Land};
+pub use multi_row::MultiRow;
+pub use pin_header::PinHeader;
+pub use round_pad::RoundPad;
+pub use transistor_outline::{ToBody, TransistorOutline};
 pub use two_pin::TwoPin;
 
 #[derive(Debug, Default)]
 pub struct PadProperties {
     pub size: Size,
     pub distance: f64,
+    pub hole: Option<f64>,
     pub courtyard: f64,
     lead_span: f64,
 }
@@ -0,0 +1,281 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+use super::silkscreen::{self, Side};
+use super::{courtyard, mask, paste, PadProperties};
+
+#[derive(Debug, Default)]
+pub struct MultiRow {
+    pad_props: PadProperties,
+    pad_props_y: PadProperties,
+    pitch: f64,
+    row_len: usize,
+    row_len_y: usize,
+    body: Rect,
+    names: Vec<String>,
+    pin1: Option<String>,
+    pin1_top: bool,
+    rounded_pads: bool,
+    tab: Option<(PadProperties, String)>,
+    thermal_pad: Option<Size>,
+}
+
+impl MultiRow {
+    /// Builds a `MultiRow` with modified body.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self
+    }
+
+    /// Draws multi-row pattern.
+    ///
+    /// Pin 1 is placed at the top of the left row, numbering runs counter-clockwise.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let pad = match self.pad_props.hole {
+            Some(hole) => Pad::new("").hole(hole, hole).layers(
+                Layer::COPPER_TOP | Layer::COPPER_BOTTOM | Layer::MASK_TOP | Layer::MASK_BOTTOM,
+            ),
+            None => Pad::new("")
+                .shape(PadShape::Rect)
+                .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP),
+        };
+        let pad_x = pad
+            .clone()
+            .size(self.pad_props.size.x, self.pad_props.size.y);
+        let pad_y = pad.size(self.pad_props_y.size.y, self.pad_props_y.size.x);
+
+        let x = self.pad_props.distance / 2.0;
+        let y = self.pad_props_y.distance / 2.0;
+        let dx = (self.row_len_y as f64 - 1.0) * self.pitch / 2.0;
+        let dy = (self.row_len as f64 - 1.0) * self.pitch / 2.0;
+        let mut positions = Vec::new();
+        // Left row: from top to bottom
+        for i in 0..self.row_len {
+            positions.push((
+                pad_x.clone().origin(-x, -dy + i as f64 * self.pitch),
+                Side::Left,
+            ));
+        }
+        // Bottom row: from left to right
+        for i in 0..self.row_len_y {
+            positions.push((
+                pad_y.clone().origin(-dx + i as f64 * self.pitch, y),
+                Side::Bottom,
+            ));
+        }
+        // Right row: from bottom to top, unless it is replaced by a tab
+        if self.tab.is_none() {
+            for i in 0..self.row_len {
+                positions.push((
+                    pad_x.clone().origin(x, dy - i as f64 * self.pitch),
+                    Side::Right,
+                ));
+            }
+        }
+        // Top row: from right to left
+        for i in 0..self.row_len_y {
+            positions.push((
+                pad_y.clone().origin(dx - i as f64 * self.pitch, -y),
+                Side::Top,
+            ));
+        }
+        let count = positions.len();
+        // Middle of the top row, which runs from right to left
+        let first = if self.names.is_empty() && self.pin1_top {
+            count - self.row_len_y + self.row_len_y / 2
+        } else {
+            0
+        };
+        let names: Vec<String> = if self.names.is_empty() {
+            (0..count)
+                .map(|i| ((i + count - first) % count + 1).to_string())
+                .collect()
+        } else {
+            self.names.clone()
+        };
+        // Positions with empty names have no lead, each pad keeps the side of its row for the pin 1 mark
+        let (mut pads, sides): (Vec<Pad>, Vec<Side>) = positions
+            .into_iter()
+            .zip(names.iter())
+            .filter(|(_, name)| !name.is_empty())
+            .map(|((pad, side), name)| (pad.name(name), side))
+            .unzip();
+        // Pin 1 index among populated pads
+        let pin1_position = match &self.pin1 {
+            Some(pin1) => names.iter().position(|name| name == pin1).unwrap_or(0),
+            None => first,
+        };
+        let pin1 = names[..pin1_position]
+            .iter()
+            .filter(|name| !name.is_empty())
+            .count();
+        // Through-hole pin 1 is marked by a square pad
+        if let Some(pad) = pads.get_mut(pin1).filter(|pad| !pad.is_smd()) {
+            pad.shape = PadShape::Rect;
+        }
+        // Rounded surface mount pads leave pin 1 the only rectangular one
+        if self.rounded_pads {
+            for (i, pad) in pads.iter_mut().enumerate() {
+                if i != pin1 && pad.is_smd() {
+                    pad.shape = PadShape::RoundRect;
+                }
+            }
+        }
+        if let Some((tab_props, name)) = &self.tab {
+            pads.push(
+                Pad::new(name)
+                    .shape(PadShape::Rect)
+                    .size(tab_props.size.x, tab_props.size.y)
+                    .origin(tab_props.distance / 2.0, 0.0)
+                    .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP),
+            );
+        }
+        if let Some(size) = &self.thermal_pad {
+            let number = pads.len() + 1;
+            pads.push(
+                Pad::new(&number.to_string())
+                    .shape(PadShape::Rect)
+                    .size(size.x, size.y)
+                    .layers(Layer::COPPER_TOP | Layer::MASK_TOP),
+            );
+        }
+
+        mask::calc(&mut pads, lib_cfg);
+        silkscreen::draw_body(drawing, &self.body, &pads, lib_cfg);
+        if let (Some(pad), Some(&side)) = (pads.get(pin1), sides.get(pin1)) {
+            silkscreen::draw_pin1(drawing, pad, side, lib_cfg);
+        }
+        courtyard::draw(
+            drawing,
+            &self.body,
+            &pads,
+            self.pad_props.courtyard.max(self.pad_props_y.courtyard),
+            lib_cfg,
+        );
+
+        // Thermal pad is the last one, it gets a grid of paste apertures instead of a full coverage
+        let apertures = if self.thermal_pad.is_some() {
+            paste::calc_apertures(pads.last().unwrap(), lib_cfg)
+        } else {
+            Vec::new()
+        };
+        drawing.add_pads(pads);
+        drawing.add_pads(apertures);
+    }
+
+    /// Builds a `MultiRow` with pad names given in counter-clockwise order.
+    ///
+    /// An empty name leaves the corresponding position unpopulated.
+    pub fn names(mut self, names: Vec<String>) -> Self {
+        self.names = names;
+        self
+    }
+
+    /// Builds a `MultiRow` with modified pad properties of the left and right rows.
+    pub fn pad_properties(mut self, pad_props: PadProperties) -> Self {
+        self.pad_props = pad_props;
+        self
+    }
+
+    /// Builds a `MultiRow` with modified pad properties of the top and bottom rows.
+    pub fn pad_properties_y(mut self, pad_props: PadProperties) -> Self {
+        self.pad_props_y = pad_props;
+        self
+    }
+
+    /// Builds a `MultiRow` with pin 1 given by its pad name.
+    ///
+    /// Without it, pin 1 is at the first position of custom names.
+    pub fn pin1(mut self, name: &str) -> Self {
+        self.pin1 = Some(name.to_string());
+        self
+    }
+
+    /// Builds a `MultiRow` with pin 1 in the middle of the top row, as in PLCC packages.
+    ///
+    /// Numbering still runs counter-clockwise. Has no effect when custom names are given.
+    pub fn pin1_top(mut self) -> Self {
+        self.pin1_top = true;
+        self
+    }
+
+    /// Builds a `MultiRow` with modified pitch.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `MultiRow` with rounded surface mount pads except the rectangular pin 1 pad.
+    pub fn rounded_pads(mut self) -> Self {
+        self.rounded_pads = true;
+        self
+    }
+
+    /// Builds a `MultiRow` with modified number of pads in the left and right rows.
+    pub fn row_len(mut self, row_len: usize) -> Self {
+        self.row_len = row_len;
+        self
+    }
+
+    /// Builds a `MultiRow` with modified number of pads in the top and bottom rows.
+    pub fn row_len_y(mut self, row_len: usize) -> Self {
+        self.row_len_y = row_len;
+        self
+    }
+
+    /// Builds a `MultiRow` with the right row replaced by a single tab pad with specified name.
+    pub fn tab(mut self, pad_props: PadProperties, name: &str) -> Self {
+        self.tab = Some((pad_props, name.to_string()));
+        self
+    }
+
+    /// Builds a `MultiRow` with an exposed thermal pad in the center.
+    pub fn thermal_pad(mut self, width: f64, height: f64) -> Self {
+        self.thermal_pad = Some(Size::new(width, height));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pin1() {
+        let lib_cfg = load_config!("../qeda.yml");
+        let pad_props = PadProperties {
+            size: Size::new(1.0, 1.0),
+            distance: 3.0,
+            ..Default::default()
+        };
+        let mut drawing = Drawing::new();
+        MultiRow::default()
+            .pad_properties(pad_props)
+            .rounded_pads()
+            .pitch(2.0)
+            .row_len(2)
+            .names(vec![
+                "D".to_string(),
+                "A".to_string(),
+                "B".to_string(),
+                "C".to_string(),
+            ])
+            .pin1("A")
+            .body(4.0, 4.0)
+            .draw(&mut drawing, &lib_cfg);
+        let rect_pads: Vec<&str> = drawing
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Pad(pad) if matches!(pad.shape, PadShape::Rect) => Some(pad.name.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(rect_pads, vec!["A"]);
+    }
+}
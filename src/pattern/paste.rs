@@ -0,0 +1,39 @@
+use crate::config::Config;
+use crate::drawing::{Layer, Pad, PadShape};
+
+use super::Ipc7351B;
+
+/// Splits the paste layer of a large pad into a grid of apertures.
+///
+/// Full paste coverage of a thermal pad makes the component float, so the total aperture area
+/// is limited by `pattern.ratio.paste-to-thermal-pad`.
+pub fn calc_apertures(pad: &Pad, lib_cfg: &Config) -> Vec<Pad> {
+    let max_aperture = lib_cfg.get_f64("pattern.maximum.paste-aperture").unwrap();
+    let ratio = lib_cfg
+        .get_f64("pattern.ratio.paste-to-thermal-pad")
+        .unwrap();
+
+    let nx = (pad.size.x / max_aperture).ceil().max(1.0) as usize;
+    let ny = (pad.size.y / max_aperture).ceil().max(1.0) as usize;
+    let cell_x = pad.size.x / nx as f64;
+    let cell_y = pad.size.y / ny as f64;
+    let k = ratio.sqrt();
+    let size_x = Ipc7351B::round_size(cell_x * k);
+    let size_y = Ipc7351B::round_size(cell_y * k);
+
+    let x0 = pad.origin.x - pad.size.x / 2.0 + cell_x / 2.0;
+    let y0 = pad.origin.y - pad.size.y / 2.0 + cell_y / 2.0;
+    let mut result = Vec::new();
+    for row in 0..ny {
+        for col in 0..nx {
+            result.push(
+                Pad::new("")
+                    .shape(PadShape::Rect)
+                    .size(size_x, size_y)
+                    .origin(x0 + col as f64 * cell_x, y0 + row as f64 * cell_y)
+                    .layers(Layer::PASTE_TOP),
+            );
+        }
+    }
+    result
+}
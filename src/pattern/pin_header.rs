@@ -0,0 +1,143 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+use super::silkscreen::{self, Side};
+use super::{courtyard, mask, PadProperties};
+
+#[derive(Debug, Default)]
+pub struct PinHeader {
+    pad_props: PadProperties,
+    pitch: f64,
+    rows: usize,
+    columns: usize,
+    row_major: bool,
+    body: Rect,
+}
+
+impl PinHeader {
+    /// Builds a `PinHeader` with modified body.
+    pub fn body(mut self, width: f64, height: f64) -> Self {
+        self.body.p.0.x = -width / 2.0;
+        self.body.p.0.y = -height / 2.0;
+        self.body.p.1.x = width / 2.0;
+        self.body.p.1.y = height / 2.0;
+        self
+    }
+
+    /// Builds a `PinHeader` with modified number of pins in a row.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Draws pin header pattern.
+    ///
+    /// Pin 1 is at the top left corner. Surface mount pads are shifted off the row line by
+    /// a half of the pad distance: alternately up and down for a single row, outwards for two rows.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let pad = match self.pad_props.hole {
+            Some(hole) => Pad::new("")
+                .hole(hole, hole)
+                .size(self.pad_props.size.x, self.pad_props.size.y)
+                .layers(
+                    Layer::COPPER_TOP | Layer::COPPER_BOTTOM | Layer::MASK_TOP | Layer::MASK_BOTTOM,
+                ),
+            None => Pad::new("")
+                .shape(PadShape::Rect)
+                .size(self.pad_props.size.y, self.pad_props.size.x)
+                .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP),
+        };
+
+        let x0 = -(self.columns as f64 - 1.0) * self.pitch / 2.0;
+        let y0 = -(self.rows as f64 - 1.0) * self.pitch / 2.0;
+        let d = self.pad_props.distance / 2.0;
+        let mut pads = Vec::new();
+        for row in 0..self.rows {
+            for col in 0..self.columns {
+                let x = x0 + col as f64 * self.pitch;
+                let y = match (pad.is_smd(), self.rows) {
+                    (false, _) => y0 + row as f64 * self.pitch,
+                    (true, 1) if col % 2 == 0 => -d,
+                    (true, 1) => d,
+                    (true, _) if row == 0 => -d,
+                    (true, _) => d,
+                };
+                let number = self.number(row, col);
+                pads.push((number, pad.clone().name(&number.to_string()).origin(x, y)));
+            }
+        }
+        pads.sort_by_key(|(number, _)| *number);
+        let mut pads: Vec<Pad> = pads.into_iter().map(|(_, pad)| pad).collect();
+        // Through-hole pin 1 is marked by a square pad
+        if let Some(first) = pads.first_mut().filter(|pad| !pad.is_smd()) {
+            first.shape = PadShape::Rect;
+        }
+
+        mask::calc(&mut pads, lib_cfg);
+        silkscreen::draw_body(drawing, &self.body, &pads, lib_cfg);
+        if let Some(first) = pads.first() {
+            silkscreen::draw_pin1(drawing, first, Side::Left, lib_cfg);
+        }
+        courtyard::draw(
+            drawing,
+            &self.body,
+            &pads,
+            self.pad_props.courtyard,
+            lib_cfg,
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Returns pin number at the specified zero-based row and column.
+    pub fn number(&self, row: usize, col: usize) -> usize {
+        if self.row_major {
+            row * self.columns + col + 1
+        } else {
+            col * self.rows + row + 1
+        }
+    }
+
+    /// Builds a `PinHeader` with modified pad properties.
+    pub fn pad_properties(mut self, pad_props: PadProperties) -> Self {
+        self.pad_props = pad_props;
+        self
+    }
+
+    /// Builds a `PinHeader` with modified pitch.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Builds a `PinHeader` with row-major pin numbering instead of zig-zag one.
+    pub fn row_major(mut self, row_major: bool) -> Self {
+        self.row_major = row_major;
+        self
+    }
+
+    /// Builds a `PinHeader` with modified number of rows.
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbering() {
+        let zigzag = PinHeader::default().rows(2).columns(5);
+        assert_eq!(zigzag.number(0, 0), 1);
+        assert_eq!(zigzag.number(1, 0), 2);
+        assert_eq!(zigzag.number(0, 4), 9);
+
+        let row_major = zigzag.row_major(true);
+        assert_eq!(row_major.number(0, 4), 5);
+        assert_eq!(row_major.number(1, 0), 6);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+use super::PadProperties;
+
+#[derive(Debug, Default)]
+pub struct RoundPad {
+    pad_props: PadProperties,
+    non_plated: bool,
+    vias: Option<(usize, f64)>,
+    keepout: Option<f64>,
+}
+
+impl RoundPad {
+    /// Draws single round pad pattern, e.g. a mounting hole or a test point.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let clearance = lib_cfg
+            .get_f64("pattern.clearance.pad-to-silkscreen")
+            .unwrap();
+        let mask = lib_cfg.get_f64("pattern.clearance.pad-to-mask").unwrap();
+        let min_ring = lib_cfg.get_f64("pattern.minimum.ring-width").unwrap();
+
+        let diameter = self.pad_props.size.x;
+        let mut pads = Vec::new();
+        let pad = match self.pad_props.hole {
+            // Non-plated hole has neither a name nor copper around
+            Some(hole) if self.non_plated => Pad::new("")
+                .hole(hole, hole)
+                .non_plated()
+                .size(hole, hole)
+                .layers(
+                    Layer::COPPER_TOP | Layer::COPPER_BOTTOM | Layer::MASK_TOP | Layer::MASK_BOTTOM,
+                ),
+            Some(hole) => Pad::new("1")
+                .hole(hole, hole)
+                .size(diameter, diameter)
+                .layers(
+                    Layer::COPPER_TOP | Layer::COPPER_BOTTOM | Layer::MASK_TOP | Layer::MASK_BOTTOM,
+                ),
+            None => Pad::new("1")
+                .size(diameter, diameter)
+                .layers(Layer::COPPER_TOP | Layer::MASK_TOP),
+        };
+        // Copper of other nets is kept out of the area by the pad clearance
+        let pad = match self.keepout {
+            Some(keepout) => {
+                let size = pad.size.x;
+                pad.clearance(((keepout - size) / 2.0).max(0.0))
+            }
+            None => pad,
+        };
+        pads.push(pad);
+        // Vias are evenly spread over the middle of the annular ring
+        if let (Some((count, via_hole)), Some(hole), false) =
+            (self.vias, self.pad_props.hole, self.non_plated)
+        {
+            let radius = (diameter + hole) / 4.0;
+            let via_diameter = via_hole + 2.0 * min_ring;
+            for i in 0..count {
+                let angle = 2.0 * std::f64::consts::PI * i as f64 / count as f64;
+                pads.push(
+                    Pad::new("1")
+                        .hole(via_hole, via_hole)
+                        .size(via_diameter, via_diameter)
+                        .origin(radius * angle.cos(), radius * angle.sin())
+                        .layers(Layer::COPPER_TOP | Layer::COPPER_BOTTOM),
+                );
+            }
+        }
+        for pad in pads.iter_mut() {
+            pad.mask = mask;
+        }
+
+        let silkscreen = diameter / 2.0 + clearance + line_width / 2.0;
+        drawing.add_circle(
+            Circle::new(0.0, 0.0, silkscreen)
+                .width(line_width)
+                .layer(Layer::SILKSCREEN_TOP),
+        );
+        drawing.add_circle(
+            Circle::new(0.0, 0.0, diameter / 2.0)
+                .width(lib_cfg.get_f64("pattern.line-width.assembly").unwrap())
+                .layer(Layer::ASSEMBLY_TOP),
+        );
+
+        // Courtyard follows the keep-out border or encloses the silkscreen ring
+        let courtyard = match self.keepout {
+            Some(keepout) => keepout / 2.0,
+            None => (diameter / 2.0 + self.pad_props.courtyard).max(silkscreen + line_width / 2.0),
+        };
+        drawing.add_circle(
+            Circle::new(0.0, 0.0, (courtyard / 0.01).round() * 0.01)
+                .width(lib_cfg.get_f64("pattern.line-width.courtyard").unwrap())
+                .layer(Layer::COURTYARD_TOP),
+        );
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `RoundPad` with a circular keep-out area of the specified diameter.
+    ///
+    /// Tracks, vias and copper fills of other nets are kept out by the pad clearance,
+    /// the courtyard is drawn along the area border.
+    pub fn keepout(mut self, diameter: f64) -> Self {
+        self.keepout = Some(diameter);
+        self
+    }
+
+    /// Builds a `RoundPad` with a non-plated hole.
+    pub fn non_plated(mut self) -> Self {
+        self.non_plated = true;
+        self
+    }
+
+    /// Builds a `RoundPad` with modified pad properties, pad diameter is taken from the size.
+    pub fn pad_properties(mut self, pad_props: PadProperties) -> Self {
+        self.pad_props = pad_props;
+        self
+    }
+
+    /// Builds a `RoundPad` with a ring of vias around the hole.
+    pub fn vias(mut self, count: usize, hole: f64) -> Self {
+        self.vias = Some((count, hole));
+        self
+    }
+}
@@ -1,7 +1,8 @@
 use crate::config::Config;
-use crate::drawing::{Drawing, Layer, Line, Pad, Rect};
+use crate::drawing::{Arc, Circle, Drawing, Layer, Line, Pad, Rect};
 
-pub fn draw_body(drawing: &mut Drawing, body: &Rect, _pads: &[Pad], lib_cfg: &Config) {
+/// Draws a body outline with the parts overlapping pads cut off.
+pub fn draw_body(drawing: &mut Drawing, body: &Rect, pads: &[Pad], lib_cfg: &Config) {
     let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
 
     let rect = body
@@ -10,6 +11,329 @@ pub fn draw_body(drawing: &mut Drawing, body: &Rect, _pads: &[Pad], lib_cfg: &Co
         .line_width(line_width)
         .layer(Layer::SILKSCREEN_TOP);
 
-    let lines: Vec<Line> = rect.to_lines();
+    draw_clipped(drawing, rect.to_lines(), pads, lib_cfg);
+}
+
+/// Draws a body outline with chamfered left corners and the parts overlapping pads cut off.
+pub fn draw_chamfered_body(
+    drawing: &mut Drawing,
+    body: &Rect,
+    chamfer: f64,
+    pads: &[Pad],
+    lib_cfg: &Config,
+) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+
+    // Chamfer grows when its line is shifted outwards by a half of the line width
+    let d = line_width / 2.0;
+    let polyline = body
+        .clone()
+        .expand(d)
+        .line_width(line_width)
+        .layer(Layer::SILKSCREEN_TOP)
+        .to_chamfered_left(chamfer + d * (2.0 - 2.0_f64.sqrt()));
+
+    draw_clipped(drawing, polyline.to_lines(), pads, lib_cfg);
+}
+
+/// Draws a round body outline inscribed into the body rectangle.
+pub fn draw_circle_body(drawing: &mut Drawing, body: &Rect, lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+
+    let x = (body.p.0.x + body.p.1.x) / 2.0;
+    let y = (body.p.0.y + body.p.1.y) / 2.0;
+    let radius = (body.p.1.x - body.p.0.x).min(body.p.1.y - body.p.0.y) / 2.0;
+    drawing.add_circle(
+        Circle::new(x, y, radius + line_width / 2.0)
+            .width(line_width)
+            .layer(Layer::SILKSCREEN_TOP),
+    );
+}
+
+/// Draws a cathode band across the body next to the specified pad, which is the left one.
+pub fn draw_cathode(drawing: &mut Drawing, body: &Rect, pad: &Pad, lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+    let clearance = lib_cfg
+        .get_f64("pattern.clearance.pad-to-silkscreen")
+        .unwrap();
+
+    // Put the band right of the pad with silkscreen clearance, but not left of the body edge
+    let x = (pad.origin.x + pad.size.x / 2.0 + clearance).max(body.p.0.x) + line_width / 2.0;
+    drawing.add_line(
+        Line::new(x, body.p.0.y, x, body.p.1.y)
+            .width(line_width)
+            .layer(Layer::SILKSCREEN_TOP),
+    );
+}
+
+/// Draws a pin 1 mark outside of the top left body corner.
+pub fn draw_corner_mark(drawing: &mut Drawing, body: &Rect, lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+    let size = lib_cfg.get_f64("pattern.polarity.size").unwrap();
+
+    let d = line_width + size / 2.0;
+    draw_polarity(drawing, body.p.0.x - d, body.p.0.y - d, lib_cfg);
+}
+
+/// Draws a polarity mark centered at the given point.
+///
+/// The mark shape and size are taken from `pattern.polarity`.
+pub fn draw_polarity(drawing: &mut Drawing, x: f64, y: f64, lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+    let mark = lib_cfg.get_str("pattern.polarity.mark").unwrap();
+    let size = lib_cfg.get_f64("pattern.polarity.size").unwrap();
+
+    let d = (size - line_width) / 2.0;
+    let lines = match mark {
+        "-" => vec![Line::new(x - d, y, x + d, y)],
+        "+" => vec![Line::new(x - d, y, x + d, y), Line::new(x, y - d, x, y + d)],
+        "x" => {
+            let d = d / 2.0_f64.sqrt();
+            vec![
+                Line::new(x - d, y - d, x + d, y + d),
+                Line::new(x - d, y + d, x + d, y - d),
+            ]
+        }
+        "o" => {
+            drawing.add_circle(
+                Circle::new(x, y, d)
+                    .width(line_width)
+                    .layer(Layer::SILKSCREEN_TOP),
+            );
+            return;
+        }
+        // Dot is a zero-length line with round ends
+        _ => {
+            drawing.add_line(
+                Line::new(x, y, x, y)
+                    .width(size)
+                    .layer(Layer::SILKSCREEN_TOP),
+            );
+            return;
+        }
+    };
+    for line in lines {
+        drawing.add_line(line.width(line_width).layer(Layer::SILKSCREEN_TOP));
+    }
+}
+
+/// Side of a pad where a mark is put.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Draws a pin 1 mark at the specified side of the pad.
+pub fn draw_pin1(drawing: &mut Drawing, pad: &Pad, side: Side, lib_cfg: &Config) {
+    let clearance = lib_cfg
+        .get_f64("pattern.clearance.pad-to-silkscreen")
+        .unwrap();
+    let size = lib_cfg.get_f64("pattern.polarity.size").unwrap();
+
+    let dx = pad.size.x / 2.0 + clearance + size / 2.0;
+    let dy = pad.size.y / 2.0 + clearance + size / 2.0;
+    let (x, y) = match side {
+        Side::Left => (pad.origin.x - dx, pad.origin.y),
+        Side::Right => (pad.origin.x + dx, pad.origin.y),
+        Side::Top => (pad.origin.x, pad.origin.y - dy),
+        Side::Bottom => (pad.origin.x, pad.origin.y + dy),
+    };
+    draw_polarity(drawing, x, y, lib_cfg);
+}
+
+/// Draws lines except the parts violating pad-to-silkscreen clearance.
+pub fn draw_clipped(drawing: &mut Drawing, lines: Vec<Line>, pads: &[Pad], lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+    let keepouts = keepouts(pads, lib_cfg);
+    let lines: Vec<Line> = lines
+        .into_iter()
+        .flat_map(|line| clip(line, &keepouts))
+        .filter(|line| line.length() >= line_width)
+        .collect();
     drawing.add_lines(lines);
 }
+
+/// Draws arcs except the parts violating pad-to-silkscreen clearance.
+pub fn draw_clipped_arcs(drawing: &mut Drawing, arcs: Vec<Arc>, pads: &[Pad], lib_cfg: &Config) {
+    let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+    let keepouts = keepouts(pads, lib_cfg);
+    for arc in arcs.into_iter().flat_map(|arc| clip_arc(arc, &keepouts)) {
+        if arc.radius * (arc.end - arc.start).abs().to_radians() >= line_width {
+            drawing.add_arc(arc);
+        }
+    }
+}
+
+// Rectangles around pads where silkscreen is not allowed
+fn keepouts(pads: &[Pad], lib_cfg: &Config) -> Vec<Rect> {
+    let line_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+    let clearance = lib_cfg
+        .get_f64("pattern.clearance.pad-to-silkscreen")
+        .unwrap();
+
+    pads.iter()
+        .map(|pad| {
+            Rect::new(
+                pad.origin.x - pad.size.x / 2.0,
+                pad.origin.y - pad.size.y / 2.0,
+                pad.origin.x + pad.size.x / 2.0,
+                pad.origin.y + pad.size.y / 2.0,
+            )
+            .expand(clearance + line_width / 2.0)
+        })
+        .collect()
+}
+
+// Cuts off the parts of the arc lying inside any of the keepout rectangles
+fn clip_arc(arc: Arc, keepouts: &[Rect]) -> Vec<Arc> {
+    let inside = |a: f64| {
+        let (sin, cos) = a.to_radians().sin_cos();
+        let x = arc.center.x + arc.radius * cos;
+        let y = arc.center.y + arc.radius * sin;
+        keepouts
+            .iter()
+            .any(|r| x > r.p.0.x && x < r.p.1.x && y > r.p.0.y && y < r.p.1.y)
+    };
+    // Angle where the arc crosses a keepout border between the angles `a` and `b`
+    let crossing = |mut a: f64, mut b: f64| {
+        let state = inside(a);
+        for _ in 0..20 {
+            let m = (a + b) / 2.0;
+            if inside(m) == state {
+                a = m;
+            } else {
+                b = m;
+            }
+        }
+        (a + b) / 2.0
+    };
+    let segment = |start: f64, end: f64| {
+        Arc::new(arc.center.x, arc.center.y, arc.radius, start, end)
+            .width(arc.width)
+            .layer(arc.layer)
+    };
+
+    // Arc is sampled by 1 degree steps, borders are refined by bisection
+    let steps = (arc.end - arc.start).abs().ceil().max(1.0) as usize;
+    let step = (arc.end - arc.start) / steps as f64;
+    let mut arcs = Vec::new();
+    let mut start = if inside(arc.start) {
+        None
+    } else {
+        Some(arc.start)
+    };
+    let mut prev = arc.start;
+    for i in 1..=steps {
+        let a = arc.start + step * i as f64;
+        match (start, inside(a)) {
+            (Some(s), true) => {
+                arcs.push(segment(s, crossing(prev, a)));
+                start = None;
+            }
+            (None, false) => start = Some(crossing(prev, a)),
+            _ => (),
+        }
+        prev = a;
+    }
+    if let Some(s) = start {
+        arcs.push(segment(s, arc.end));
+    }
+    arcs
+}
+
+// Cuts off the parts of the line lying inside any of the keepout rectangles
+fn clip(line: Line, keepouts: &[Rect]) -> Vec<Line> {
+    let (x0, y0) = (line.p.0.x, line.p.0.y);
+    let dx = line.p.1.x - x0;
+    let dy = line.p.1.y - y0;
+
+    // Parametric intervals covered by keepouts (Liang-Barsky)
+    let mut cuts: Vec<(f64, f64)> = keepouts
+        .iter()
+        .filter_map(|r| {
+            let mut t0 = 0.0_f64;
+            let mut t1 = 1.0_f64;
+            let edges = [
+                (-dx, x0 - r.p.0.x),
+                (dx, r.p.1.x - x0),
+                (-dy, y0 - r.p.0.y),
+                (dy, r.p.1.y - y0),
+            ];
+            for &(p, q) in edges.iter() {
+                if p == 0.0 {
+                    if q < 0.0 {
+                        return None;
+                    }
+                } else if p < 0.0 {
+                    t0 = t0.max(q / p);
+                } else {
+                    t1 = t1.min(q / p);
+                }
+            }
+            if t0 < t1 {
+                Some((t0, t1))
+            } else {
+                None
+            }
+        })
+        .collect();
+    cuts.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let segment = |t0: f64, t1: f64| {
+        Line::new(x0 + t0 * dx, y0 + t0 * dy, x0 + t1 * dx, y0 + t1 * dy)
+            .width(line.width)
+            .layer(line.layer)
+    };
+    let mut lines = Vec::new();
+    let mut t = 0.0;
+    for (t0, t1) in cuts {
+        if t0 > t {
+            lines.push(segment(t, t0));
+        }
+        t = t1.max(t);
+    }
+    if t < 1.0 {
+        lines.push(segment(t, 1.0));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clip_line() {
+        let keepouts = vec![
+            Rect::new(-1.0, -1.0, 1.0, 1.0),
+            Rect::new(2.0, -1.0, 3.0, 1.0),
+        ];
+        let lines = clip(Line::new(-5.0, 0.0, 5.0, 0.0), &keepouts);
+        assert_eq!(lines.len(), 3);
+        assert_eq!((lines[0].p.0.x, lines[0].p.1.x), (-5.0, -1.0));
+        assert_eq!((lines[1].p.0.x, lines[1].p.1.x), (1.0, 2.0));
+        assert_eq!((lines[2].p.0.x, lines[2].p.1.x), (3.0, 5.0));
+
+        let lines = clip(Line::new(-5.0, 2.0, 5.0, 2.0), &keepouts);
+        assert_eq!(lines.len(), 1);
+    }
+
+    #[test]
+    fn clip_arc() {
+        let keepouts = vec![Rect::new(-0.5, 0.5, 0.5, 1.5)];
+        let arcs = super::clip_arc(Arc::new(0.0, 0.0, 1.0, 0.0, 180.0), &keepouts);
+        let angles: Vec<(f64, f64)> = arcs
+            .iter()
+            .map(|a| {
+                (
+                    (a.start * 100.0).round() / 100.0,
+                    (a.end * 100.0).round() / 100.0,
+                )
+            })
+            .collect();
+        assert_eq!(angles, vec![(0.0, 60.0), (120.0, 180.0)]);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::config::Config;
+use crate::drawing::*;
+
+use super::{courtyard, mask, silkscreen, PadProperties};
+
+/// Body shape of a transistor outline (TO) package.
+#[derive(Debug)]
+pub enum ToBody {
+    /// Round molded body with a flat front side at the specified distance from the center, e.g. TO-92
+    Flat { radius: f64, flat: f64 },
+    /// Round metal can with a tab of the specified width and length at the angle (in degrees), e.g. TO-39
+    Can { radius: f64, angle: f64, tab: Size },
+    /// Rectangular body with an optional heatsink tab of the specified thickness at its top, e.g. TO-220
+    Rect { rect: Rect, tab: Option<f64> },
+}
+
+impl Default for ToBody {
+    #[inline]
+    fn default() -> Self {
+        ToBody::Rect {
+            rect: Rect::default(),
+            tab: None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TransistorOutline {
+    pad_props: PadProperties,
+    leads: Vec<Point>,
+    body: ToBody,
+    hole: Option<(Point, f64)>,
+}
+
+impl TransistorOutline {
+    /// Builds a `TransistorOutline` with modified body.
+    pub fn body(mut self, body: ToBody) -> Self {
+        self.body = body;
+        self
+    }
+
+    /// Draws transistor outline pattern.
+    ///
+    /// Pads are numbered in the order of leads, pin 1 is marked by a square pad.
+    pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
+        super::add_attributes(drawing, lib_cfg);
+
+        let size = &self.pad_props.size;
+        let hole = self.pad_props.hole.unwrap_or_default();
+        let shape = if size.x == size.y {
+            PadShape::Circle
+        } else {
+            PadShape::Oval
+        };
+        let mut pads: Vec<Pad> = self
+            .leads
+            .iter()
+            .enumerate()
+            .map(|(i, lead)| {
+                Pad::new(&(i + 1).to_string())
+                    .shape(shape.clone())
+                    .hole(hole, hole)
+                    .size(size.x, size.y)
+                    .origin(lead.x, lead.y)
+                    .layers(
+                        Layer::COPPER_TOP
+                            | Layer::COPPER_BOTTOM
+                            | Layer::MASK_TOP
+                            | Layer::MASK_BOTTOM,
+                    )
+            })
+            .collect();
+        if let Some(first) = pads.first_mut() {
+            first.shape = PadShape::Rect;
+        }
+        if let Some((origin, diameter)) = &self.hole {
+            pads.push(
+                Pad::new("")
+                    .hole(*diameter, *diameter)
+                    .non_plated()
+                    .size(*diameter, *diameter)
+                    .origin(origin.x, origin.y)
+                    .layers(
+                        Layer::COPPER_TOP
+                            | Layer::COPPER_BOTTOM
+                            | Layer::MASK_TOP
+                            | Layer::MASK_BOTTOM,
+                    ),
+            );
+        }
+        mask::calc(&mut pads, lib_cfg);
+
+        let silkscreen_width = lib_cfg.get_f64("pattern.line-width.silkscreen").unwrap();
+        let (arcs, lines) = self.outline(silkscreen_width / 2.0);
+        let arcs = arcs
+            .into_iter()
+            .map(|arc| arc.width(silkscreen_width).layer(Layer::SILKSCREEN_TOP))
+            .collect();
+        silkscreen::draw_clipped_arcs(drawing, arcs, &pads, lib_cfg);
+        let lines = lines
+            .into_iter()
+            .map(|line| line.width(silkscreen_width).layer(Layer::SILKSCREEN_TOP))
+            .collect();
+        silkscreen::draw_clipped(drawing, lines, &pads, lib_cfg);
+
+        let assembly_width = lib_cfg.get_f64("pattern.line-width.assembly").unwrap();
+        let (arcs, lines) = self.outline(0.0);
+        for arc in arcs {
+            drawing.add_arc(arc.width(assembly_width).layer(Layer::ASSEMBLY_TOP));
+        }
+        for line in lines {
+            drawing.add_line(line.width(assembly_width).layer(Layer::ASSEMBLY_TOP));
+        }
+
+        let bounds = match &self.body {
+            ToBody::Flat { radius, flat } => Rect::new(-radius, -radius, *radius, *flat),
+            ToBody::Can { radius, tab, .. } => {
+                let r = radius.max(radius + tab.y);
+                Rect::new(-r, -r, r, r)
+            }
+            ToBody::Rect { rect, .. } => rect.clone(),
+        };
+        courtyard::draw(drawing, &bounds, &pads, self.pad_props.courtyard, lib_cfg);
+
+        drawing.add_pads(pads);
+    }
+
+    /// Builds a `TransistorOutline` with a non-plated mounting hole.
+    pub fn hole(mut self, x: f64, y: f64, diameter: f64) -> Self {
+        self.hole = Some((Point::new(x, y), diameter));
+        self
+    }
+
+    /// Builds a `TransistorOutline` with modified lead positions.
+    pub fn leads(mut self, leads: Vec<Point>) -> Self {
+        self.leads = leads;
+        self
+    }
+
+    /// Builds a `TransistorOutline` with modified pad properties.
+    pub fn pad_properties(mut self, pad_props: PadProperties) -> Self {
+        self.pad_props = pad_props;
+        self
+    }
+
+    // Body outline shifted outwards by `d`
+    fn outline(&self, d: f64) -> (Vec<Arc>, Vec<Line>) {
+        match &self.body {
+            ToBody::Flat { radius, flat } => {
+                let r = radius + d;
+                let y = flat + d;
+                // Arc runs over the back side between the ends of the flat side
+                let x = (r * r - y * y).max(0.0).sqrt();
+                let a = y.atan2(x).to_degrees();
+                (
+                    vec![Arc::new(0.0, 0.0, r, 180.0 - a, 360.0 + a)],
+                    vec![Line::new(-x, y, x, y)],
+                )
+            }
+            ToBody::Can { radius, angle, tab } => {
+                let r = radius + d;
+                let w = tab.x / 2.0 + d;
+                let l = radius + tab.y + d;
+                // Tab is drawn in the local frame rotated to its angle
+                let x0 = (r * r - w * w).max(0.0).sqrt();
+                let a = w.atan2(x0).to_degrees();
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let point = |x: f64, y: f64| Point::new(x * cos - y * sin, x * sin + y * cos);
+                let polyline =
+                    Polyline::new(vec![point(x0, -w), point(l, -w), point(l, w), point(x0, w)]);
+                (
+                    vec![Arc::new(0.0, 0.0, r, angle + a, angle + 360.0 - a)],
+                    polyline.to_lines(),
+                )
+            }
+            ToBody::Rect { rect, tab } => {
+                let mut lines = rect.clone().expand(d).to_lines();
+                if let Some(tab) = tab {
+                    let y = rect.p.0.y + tab;
+                    lines.push(Line::new(rect.p.0.x - d, y, rect.p.1.x + d, y));
+                }
+                (Vec::new(), lines)
+            }
+        }
+    }
+}
@@ -1,15 +1,29 @@
 use crate::config::Config;
 use crate::drawing::*;
 
-use super::{mask, silkscreen, PadProperties};
+use super::silkscreen::{self, Side};
+use super::{assembly, courtyard, mask, PadProperties};
 
 #[derive(Debug, Default)]
 pub struct TwoPin {
     pad_props: PadProperties,
     body: Rect,
+    chamfer: Option<f64>,
+    names: Option<(String, String)>,
+    round_body: bool,
+    polarized: bool,
+    cathode: bool,
+    courtyard: bool,
+    assembly: bool,
 }
 
 impl TwoPin {
+    /// Builds a `TwoPin` with a body outline on the assembly layer.
+    pub fn assembly(mut self, assembly: bool) -> Self {
+        self.assembly = assembly;
+        self
+    }
+
     /// Builds a `TwoPin` with modified body.
     pub fn body(mut self, width: f64, height: f64) -> Self {
         self.body.p.0.x = -width / 2.0;
@@ -19,30 +33,106 @@ impl TwoPin {
         self
     }
 
+    /// Builds a `TwoPin` with a round body of the specified diameter.
+    pub fn body_diameter(mut self, diameter: f64) -> Self {
+        self.round_body = true;
+        self.body(diameter, diameter)
+    }
+
+    /// Builds a `TwoPin` with a cathode band at pin 1.
+    pub fn cathode(mut self, cathode: bool) -> Self {
+        self.cathode = cathode;
+        self
+    }
+
+    /// Builds a `TwoPin` with the body chamfered at the pin 1 side.
+    pub fn chamfer(mut self, chamfer: f64) -> Self {
+        self.chamfer = Some(chamfer);
+        self
+    }
+
+    /// Builds a `TwoPin` with a courtyard outline.
+    pub fn courtyard(mut self, courtyard: bool) -> Self {
+        self.courtyard = courtyard;
+        self
+    }
+
     /// Draws two pin pattern.
     pub fn draw(&self, drawing: &mut Drawing, lib_cfg: &Config) {
         super::add_attributes(drawing, lib_cfg);
 
-        let pad_left = Pad::new("1")
-            .shape(PadShape::Rect)
+        let pad = match self.pad_props.hole {
+            Some(hole) => Pad::new("1").hole(hole, hole).layers(
+                Layer::COPPER_TOP | Layer::COPPER_BOTTOM | Layer::MASK_TOP | Layer::MASK_BOTTOM,
+            ),
+            None => Pad::new("1")
+                .shape(PadShape::Rect)
+                .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP),
+        };
+        let (name_left, name_right) = match &self.names {
+            Some((left, right)) => (left.as_str(), right.as_str()),
+            None => ("1", "2"),
+        };
+        let mut pad_left = pad
+            .name(name_left)
             .size(self.pad_props.size.x, self.pad_props.size.y)
-            .origin(-self.pad_props.distance / 2.0, 0.0)
-            .layers(Layer::COPPER_TOP | Layer::MASK_TOP | Layer::PASTE_TOP);
+            .origin(-self.pad_props.distance / 2.0, 0.0);
         let pad_right = pad_left
             .clone()
-            .name("2")
+            .name(name_right)
             .origin(self.pad_props.distance / 2.0, 0.0);
+        // Through-hole pin 1 of a polarized part is marked by a square pad
+        if self.polarized && !pad_left.is_smd() {
+            pad_left.shape = PadShape::Rect;
+        }
 
         let mut pads = vec![pad_left, pad_right];
         mask::calc(&mut pads, lib_cfg);
-        silkscreen::draw_body(drawing, &self.body, &pads, lib_cfg);
+        if self.round_body {
+            silkscreen::draw_circle_body(drawing, &self.body, lib_cfg);
+        } else if let Some(chamfer) = self.chamfer {
+            silkscreen::draw_chamfered_body(drawing, &self.body, chamfer, &pads, lib_cfg);
+            assembly::draw_chamfered_body(drawing, &self.body, chamfer, lib_cfg);
+        } else {
+            silkscreen::draw_body(drawing, &self.body, &pads, lib_cfg);
+            if self.assembly {
+                assembly::draw_body(drawing, &self.body, lib_cfg);
+            }
+        }
+        if self.cathode {
+            silkscreen::draw_cathode(drawing, &self.body, &pads[0], lib_cfg);
+            assembly::draw_cathode(drawing, &self.body, lib_cfg);
+        } else if self.polarized {
+            silkscreen::draw_pin1(drawing, &pads[0], Side::Left, lib_cfg);
+        }
+        if self.courtyard {
+            courtyard::draw(
+                drawing,
+                &self.body,
+                &pads,
+                self.pad_props.courtyard,
+                lib_cfg,
+            );
+        }
 
         drawing.add_pads(pads);
     }
 
+    /// Builds a `TwoPin` with modified names of the left and right pads.
+    pub fn names(mut self, left: &str, right: &str) -> Self {
+        self.names = Some((left.to_string(), right.to_string()));
+        self
+    }
+
     /// Builds a `TwoPin` with modified pad properties.
     pub fn pad_properties(mut self, pad_props: PadProperties) -> Self {
         self.pad_props = pad_props;
         self
     }
+
+    /// Builds a `TwoPin` with a pin 1 polarity mark.
+    pub fn polarized(mut self, polarized: bool) -> Self {
+        self.polarized = polarized;
+        self
+    }
 }
@@ -153,6 +153,67 @@ impl Pinout {
         }
     }
 
+    /// Parses pin number(s) from `Config`'s value.
+    ///
+    /// Ranges like `A1..K10` are expanded row by row using JEDEC row letters.
+    pub fn parse_number(&self, number: &Value) -> Result<Vec<String>> {
+        let mut result = Vec::new();
+        match number {
+            Value::Number(n) => {
+                // Numbers are stored as floats, so `4` would become `4.0` otherwise
+                result.push(n.as_f64().unwrap().to_string());
+            }
+            Value::String(s) => {
+                let s = s.to_uppercase();
+                let re = Regex::new(r"([A-Z]{0,2})(\d+)\s*\.\.\s*([A-Z]{0,2})(\d+)").unwrap();
+                if re.is_match(&s) {
+                    let caps = re
+                        .captures(&s)
+                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
+                    let row_begin = self
+                        .letters
+                        .iter()
+                        .position(|s| s.eq(&caps[1]))
+                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
+                    let mut row_end = self
+                        .letters
+                        .iter()
+                        .position(|s| s.eq(&caps[3]))
+                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
+                    if row_end < row_begin {
+                        row_end = row_begin;
+                    }
+                    let col_begin = caps[2].parse::<usize>()?;
+                    let col_end = caps[4].parse::<usize>()?;
+                    ensure!(
+                        col_begin < col_end,
+                        QedaError::InvalidPinNumber(s.to_string())
+                    );
+                    for row in row_begin..=row_end {
+                        for col in col_begin..=col_end {
+                            result.push(format!("{}{}", self.letters[row], col));
+                        }
+                    }
+                } else {
+                    result.push(s);
+                }
+            }
+            Value::Array(a) => {
+                for n in a {
+                    let mut sub_numbers = self.parse_number(n)?;
+                    result.append(&mut sub_numbers);
+                }
+            }
+            _ => (), // TODO: Return the error about unexpected type
+        }
+        Ok(result)
+    }
+
+    /// Returns the JEDEC row name (`A`, `B`, ..., `Y`, `AA`, ...) for the zero-based row index.
+    pub fn row_name(&self, row: usize) -> Option<&str> {
+        self.letters.get(row + 1).map(|s| s.as_str())
+    }
+
     // Add pins from `Config`'s value
     fn add_pins(&mut self, name: &str, value: &Value) -> Result<Vec<usize>> {
         let mut result = Vec::new();
@@ -224,59 +285,6 @@ impl Pinout {
 
         Ok(result)
     }
-
-    // Parse pin number(s) from string
-    fn parse_number(&self, number: &Value) -> Result<Vec<String>> {
-        let mut result = Vec::new();
-        match number {
-            Value::Number(n) => {
-                result.push(n.to_string());
-            }
-            Value::String(s) => {
-                let s = s.to_uppercase();
-                let re = Regex::new(r"([A-Z]{0,2})(\d+)\s*\.\.\s*([A-Z]{0,2})(\d+)").unwrap();
-                if re.is_match(&s) {
-                    let caps = re
-                        .captures(&s)
-                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
-                    let row_begin = self
-                        .letters
-                        .iter()
-                        .position(|s| s.eq(&caps[1]))
-                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
-                    let mut row_end = self
-                        .letters
-                        .iter()
-                        .position(|s| s.eq(&caps[3]))
-                        .ok_or_else(|| QedaError::InvalidPinNumber(s.to_string()))?;
-                    if row_end < row_begin {
-                        row_end = row_begin;
-                    }
-                    let col_begin = caps[2].parse::<usize>()?;
-                    let col_end = caps[4].parse::<usize>()?;
-                    ensure!(
-                        col_begin < col_end,
-                        QedaError::InvalidPinNumber(s.to_string())
-                    );
-                    for row in row_begin..=row_end {
-                        for col in col_begin..=col_end {
-                            result.push(format!("{}{}", self.letters[row], col));
-                        }
-                    }
-                } else {
-                    result.push(s);
-                }
-            }
-            Value::Array(a) => {
-                for n in a {
-                    let mut sub_numbers = self.parse_number(n)?;
-                    result.append(&mut sub_numbers);
-                }
-            }
-            _ => (), // TODO: Return the error about unexpected type
-        }
-        Ok(result)
-    }
 }
 
 impl Default for Pinout {
@@ -320,6 +328,9 @@ mod tests {
         assert_eq!(*pinout.groups.get("E1").unwrap(), vec!(12));
         assert_eq!(*pinout.groups.get("E2").unwrap(), vec!(13));
 
+        assert_eq!(pinout.get_first("A").unwrap().number, "1");
+        assert_eq!(pinout.get_first("C").unwrap().number, "4");
+
         Ok(())
     }
 
@@ -342,4 +353,14 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn row_name() {
+        let pinout = Pinout::new();
+        assert_eq!(pinout.row_name(0), Some("A"));
+        assert_eq!(pinout.row_name(8), Some("J"));
+        assert_eq!(pinout.row_name(19), Some("Y"));
+        assert_eq!(pinout.row_name(20), Some("Z"));
+        assert_eq!(pinout.row_name(21), Some("AA"));
+    }
 }
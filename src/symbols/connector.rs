@@ -0,0 +1,102 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::packages;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::SymbolHandler;
+
+pub struct ConnectorSymbol {}
+
+impl ConnectorSymbol {
+    pub fn new() -> ConnectorSymbol {
+        ConnectorSymbol {}
+    }
+}
+
+impl SymbolHandler for ConnectorSymbol {
+    fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw connector symbol");
+
+        let pitch = lib_cfg.get_f64("symbol.pitch").unwrap();
+        let pinout = Pinout::from_config(comp_cfg)?;
+        let pin_header = packages::pin_header(comp_cfg)?;
+        let rows = comp_cfg.get_u64("package.rows").unwrap_or(1) as usize;
+        let columns = comp_cfg.get_u64("package.columns")? as usize;
+
+        // Two-row headers get a pin side per row, other ones are listed at the left side
+        let (left, right): (Vec<usize>, Vec<usize>) = if rows == 2 {
+            (
+                (0..columns).map(|col| pin_header.number(0, col)).collect(),
+                (0..columns).map(|col| pin_header.number(1, col)).collect(),
+            )
+        } else {
+            ((1..=rows * columns).collect(), Vec::new())
+        };
+
+        let half_width = 2.0;
+        let pin_len = pitch;
+        let top = (left.len().max(right.len()) as f64 - 1.0) * pitch / 2.0;
+        let body = Rect::new(
+            -half_width,
+            top + pitch / 2.0,
+            half_width,
+            -top - pitch / 2.0,
+        );
+
+        let mut part = Drawing::new();
+        part.add_attribute(
+            Attribute::new("ref-des", "J")
+                .origin(0.0, body.p.1.y + 1.0)
+                .align(HAlign::Center, VAlign::Bottom),
+        );
+        part.add_attribute(
+            Attribute::new("value", "Conn")
+                .origin(0.0, body.p.0.y - 1.0)
+                .align(HAlign::Center, VAlign::Top),
+        );
+        part.add_lines(body.to_lines());
+
+        let pin = |number: usize| {
+            let number = number.to_string();
+            let pin = pinout
+                .pins
+                .iter()
+                .find(|pin| pin.number == number)
+                .cloned()
+                .unwrap_or_else(|| Pin::new(&number, &number));
+            if pin.kind == PinKind::UNSPECIFIED {
+                pin.kind(PinKind::PASSIVE)
+            } else {
+                pin
+            }
+        };
+        for (i, number) in left.into_iter().enumerate() {
+            let y = top - i as f64 * pitch;
+            let line = Line::new(-half_width - pin_len, y, -half_width, y);
+            part.add_symbol_pin(SymbolPin::new(
+                pin(number),
+                HAlign::Left,
+                VAlign::Middle,
+                &line,
+            ));
+        }
+        for (i, number) in right.into_iter().enumerate() {
+            let y = top - i as f64 * pitch;
+            let line = Line::new(half_width, y, half_width + pin_len, y);
+            part.add_symbol_pin(SymbolPin::new(
+                pin(number),
+                HAlign::Right,
+                VAlign::Middle,
+                &line,
+            ));
+        }
+
+        let mut result = Symbol::new();
+        result.show_pin_numbers = true;
+        result.show_pin_names = !pinout.pins.is_empty();
+        result.add_part(part);
+        Ok(result)
+    }
+}
@@ -0,0 +1,192 @@
+use std::str::FromStr;
+
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::SymbolHandler;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DiodeVariant {
+    Rectifier,
+    Zener,
+    Schottky,
+    Tvs,
+    Led,
+}
+
+impl FromStr for DiodeVariant {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "rectifier" => DiodeVariant::Rectifier,
+            "zener" => DiodeVariant::Zener,
+            "schottky" => DiodeVariant::Schottky,
+            "tvs" => DiodeVariant::Tvs,
+            "led" => DiodeVariant::Led,
+            _ => bail!(QedaError::InvalidSymbolVariant(s.to_string())),
+        })
+    }
+}
+
+impl DiodeVariant {
+    /// Returns the variant from the component config or the default one.
+    pub fn from_config(comp_cfg: &Config, default: DiodeVariant) -> Result<Self> {
+        match comp_cfg.get_str("symbol.variant") {
+            Ok(variant) => DiodeVariant::from_str(variant),
+            Err(_) => Ok(default),
+        }
+    }
+
+    /// Returns the distance from the body center to the pin end.
+    pub fn half_length(&self) -> f64 {
+        match self {
+            DiodeVariant::Tvs => 2.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Returns the height of the body above the axis.
+    pub fn top(&self) -> f64 {
+        match self {
+            DiodeVariant::Led => 2.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Returns lines of the body centered at `cx` with the cathode directed to `dir` (±1).
+    pub fn lines(&self, cx: f64, dir: f64) -> Vec<Line> {
+        let polyline = |points: &[(f64, f64)]| {
+            Polyline::new(
+                points
+                    .iter()
+                    .map(|&(x, y)| Point::new(cx + dir * x, y))
+                    .collect(),
+            )
+        };
+        let triangle = |base: f64, tip: f64| {
+            polyline(&[(base, 1.0), (tip, 0.0), (base, -1.0)])
+                .closed()
+                .to_lines()
+        };
+        let bar = |b: f64| match self {
+            DiodeVariant::Zener | DiodeVariant::Tvs => {
+                polyline(&[(b + 0.4, 1.0), (b, 1.0), (b, -1.0), (b - 0.4, -1.0)])
+            }
+            DiodeVariant::Schottky => polyline(&[
+                (b + 0.4, 0.6),
+                (b + 0.4, 1.0),
+                (b, 1.0),
+                (b, -1.0),
+                (b - 0.4, -1.0),
+                (b - 0.4, -0.6),
+            ]),
+            _ => polyline(&[(b, 1.0), (b, -1.0)]),
+        };
+
+        let mut result = Vec::new();
+        if *self == DiodeVariant::Tvs {
+            result.append(&mut triangle(-2.0, 0.0));
+            result.append(&mut triangle(2.0, 0.0));
+            result.append(&mut bar(0.0).to_lines());
+        } else {
+            result.append(&mut triangle(-1.0, 1.0));
+            result.append(&mut bar(1.0).to_lines());
+        }
+        if *self == DiodeVariant::Led {
+            // Emission arrows
+            for &x in &[-0.4, 0.4] {
+                result.append(&mut polyline(&[(x, 1.2), (x + 0.8, 2.0)]).to_lines());
+                result.append(
+                    &mut polyline(&[(x + 0.4, 2.0), (x + 0.8, 2.0), (x + 0.8, 1.6)]).to_lines(),
+                );
+            }
+        }
+        result
+    }
+}
+
+/// Returns the first pin of the group treating unspecified kind as passive.
+pub fn passive(pinout: &Pinout, name: &str) -> Pin {
+    let pin = pinout.get_first(name).cloned().unwrap();
+    if pin.kind == PinKind::UNSPECIFIED {
+        pin.kind(PinKind::PASSIVE)
+    } else {
+        pin
+    }
+}
+
+pub struct DiodeSymbol {
+    variant: DiodeVariant,
+}
+
+impl DiodeSymbol {
+    pub fn new() -> DiodeSymbol {
+        DiodeSymbol {
+            variant: DiodeVariant::Rectifier,
+        }
+    }
+
+    /// Builds a `DiodeSymbol` with modified default variant.
+    pub fn variant(mut self, variant: DiodeVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+}
+
+impl SymbolHandler for DiodeSymbol {
+    fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw diode symbol");
+
+        let pitch = lib_cfg.get_f64("symbol.pitch").unwrap();
+        let variant = DiodeVariant::from_config(comp_cfg, self.variant)?;
+        let mut pinout = Pinout::from_config(comp_cfg)?;
+        if !pinout.groups.contains_key("K") {
+            pinout.add_pin(Pin::new("K", "1").kind(PinKind::PASSIVE));
+        }
+        if !pinout.groups.contains_key("A") {
+            pinout.add_pin(Pin::new("A", "2").kind(PinKind::PASSIVE));
+        }
+
+        let half = variant.half_length();
+        let value = if variant == DiodeVariant::Led {
+            "LED"
+        } else {
+            "Diode"
+        };
+
+        let mut part = Drawing::new();
+        part.add_attribute(
+            Attribute::new("ref-des", "D")
+                .origin(0.0, variant.top() + 1.0)
+                .align(HAlign::Center, VAlign::Bottom),
+        );
+        part.add_attribute(
+            Attribute::new("value", value)
+                .origin(0.0, -2.0)
+                .align(HAlign::Center, VAlign::Top),
+        );
+        part.add_lines(variant.lines(0.0, 1.0));
+
+        let line = Line::new(-half - pitch, 0.0, -half, 0.0);
+        part.add_symbol_pin(SymbolPin::new(
+            passive(&pinout, "A"),
+            HAlign::Left,
+            VAlign::Middle,
+            &line,
+        ));
+        let line = Line::new(half, 0.0, half + pitch, 0.0);
+        part.add_symbol_pin(SymbolPin::new(
+            passive(&pinout, "K"),
+            HAlign::Right,
+            VAlign::Middle,
+            &line,
+        ));
+
+        let mut result = Symbol::new();
+        result.add_part(part);
+        Ok(result)
+    }
+}
@@ -0,0 +1,168 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::transistor::{arrow, required_pin, vertical_pin};
+use super::SymbolHandler;
+
+pub struct FetSymbol {}
+
+impl FetSymbol {
+    pub fn new() -> FetSymbol {
+        FetSymbol {}
+    }
+}
+
+impl SymbolHandler for FetSymbol {
+    fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw FET symbol");
+
+        let pitch = lib_cfg.get_f64("symbol.pitch").unwrap();
+        let variant = comp_cfg.get_str("symbol.variant").unwrap_or("mosfet");
+        let jfet = match variant {
+            "mosfet" => false,
+            "jfet" => true,
+            _ => bail!(QedaError::InvalidSymbolVariant(variant.to_string())),
+        };
+        let channel = comp_cfg.get_str("symbol.channel").unwrap_or("n");
+        // P-channel is drawn upside down to keep the source at the top
+        let sy = match channel {
+            "n" => 1.0,
+            "p" => -1.0,
+            _ => bail!(QedaError::InvalidSymbolVariant(channel.to_string())),
+        };
+        let mode = comp_cfg.get_str("symbol.mode").unwrap_or("enhancement");
+        let depletion = match mode {
+            "enhancement" => false,
+            "depletion" => true,
+            _ => bail!(QedaError::InvalidSymbolVariant(mode.to_string())),
+        };
+        let body_diode = !jfet && comp_cfg.get_bool("symbol.body-diode").unwrap_or(false);
+
+        let pinout = Pinout::from_config(comp_cfg)?;
+        let gate = required_pin(&pinout, "G")?;
+        let drain = required_pin(&pinout, "D")?;
+        let source = required_pin(&pinout, "S")?;
+
+        let mut part = Drawing::new();
+        let x = if body_diode { 3.0 } else { 2.0 };
+        part.add_attribute(
+            Attribute::new("ref-des", "Q")
+                .origin(x, 0.5)
+                .align(HAlign::Left, VAlign::Bottom),
+        );
+        part.add_attribute(
+            Attribute::new("value", "FET")
+                .origin(x, -0.5)
+                .align(HAlign::Left, VAlign::Top),
+        );
+
+        // Lines are described for N-channel and mirrored vertically for P-channel
+        let line = |x0: f64, y0: f64, x1: f64, y1: f64| Line::new(x0, sy * y0, x1, sy * y1);
+        part.add_line(line(0.0, 1.0, 1.0, 1.0));
+        part.add_line(line(0.0, -1.0, 1.0, -1.0));
+        let gate_x = if jfet {
+            part.add_line(line(0.0, -1.3, 0.0, 1.3));
+            if sy > 0.0 {
+                part.add_lines(arrow(-0.1, 0.0, 1.0, 0.0));
+            } else {
+                part.add_lines(arrow(-0.9, 0.0, -1.0, 0.0));
+            }
+            0.0
+        } else {
+            part.add_line(line(-0.4, -1.0, -0.4, 1.0));
+            if depletion {
+                part.add_line(line(0.0, -1.3, 0.0, 1.3));
+            } else {
+                for &y in &[1.0, 0.0, -1.0] {
+                    part.add_line(line(0.0, y - 0.3, 0.0, y + 0.3));
+                }
+            }
+            // Substrate connected to the source
+            part.add_line(line(0.0, 0.0, 1.0, 0.0));
+            part.add_line(line(1.0, 0.0, 1.0, -1.0));
+            if sy > 0.0 {
+                part.add_lines(arrow(0.1, 0.0, -1.0, 0.0));
+            } else {
+                part.add_lines(arrow(0.9, 0.0, 1.0, 0.0));
+            }
+            -0.4
+        };
+        if body_diode {
+            part.add_line(line(1.0, 1.0, 2.0, 1.0));
+            part.add_line(line(2.0, 1.0, 2.0, -1.0));
+            part.add_line(line(2.0, -1.0, 1.0, -1.0));
+            // Cathode is directed to the drain for N-channel and to the source for P-channel,
+            // so the diode is not mirrored and always points upwards
+            part.add_line(Line::new(1.6, -0.3, 2.4, -0.3));
+            part.add_line(Line::new(2.4, -0.3, 2.0, 0.3));
+            part.add_line(Line::new(2.0, 0.3, 1.6, -0.3));
+            part.add_line(Line::new(1.6, 0.3, 2.4, 0.3));
+        }
+
+        let line = Line::new(-pitch, 0.0, gate_x, 0.0);
+        part.add_symbol_pin(SymbolPin::new(gate, HAlign::Left, VAlign::Middle, &line));
+        part.add_symbol_pin(vertical_pin(drain, 1.0, sy, pitch));
+        part.add_symbol_pin(vertical_pin(source, 1.0, -sy, pitch));
+
+        let mut result = Symbol::new();
+        result.add_part(part);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the name of the pin the body diode's cathode is directed to
+    fn cathode_pin(channel: &str) -> Result<String> {
+        let comp_cfg = Config::from_yaml(&format!(
+            r"
+        symbol:
+          channel: {}
+          body-diode: true
+        pinout:
+          G: 1
+          D: 2
+          S: 3
+        ",
+            channel
+        ))?;
+        let lib_cfg = load_config!("../qeda.yml");
+        let symbol = FetSymbol::new().draw(&comp_cfg, &lib_cfg)?;
+        let part = &symbol.parts[0];
+
+        // Triangle tip is the only point of the diode on its vertical axis
+        let tip_y = part
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Line(l) => Some(vec![&l.p.0, &l.p.1]),
+                _ => None,
+            })
+            .flatten()
+            .find(|p| p.x == 2.0 && p.y.abs() < 1.0)
+            .unwrap()
+            .y;
+        let pin = part
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::SymbolPin(p) if p.origin.y * tip_y > 0.0 => Some(p.pin.name.clone()),
+                _ => None,
+            })
+            .next()
+            .unwrap();
+        Ok(pin)
+    }
+
+    #[test]
+    fn body_diode() -> Result<()> {
+        assert_eq!(cathode_pin("n")?, "D");
+        assert_eq!(cathode_pin("p")?, "S");
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::symbol::Symbol;
+
+use super::power::power_pin;
+use super::SymbolHandler;
+
+pub struct GroundSymbol {}
+
+impl GroundSymbol {
+    pub fn new() -> GroundSymbol {
+        GroundSymbol {}
+    }
+}
+
+impl SymbolHandler for GroundSymbol {
+    fn draw(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw ground symbol");
+
+        let net = comp_cfg.get_str("name")?;
+        let variant = comp_cfg.get_str("symbol.variant").unwrap_or("signal");
+
+        let mut part = Drawing::new();
+        part.add_attribute(
+            Attribute::new("ref-des", "#PWR")
+                .origin(0.0, 1.0)
+                .align(HAlign::Center, VAlign::Bottom)
+                .visibility(Visibility(false)),
+        );
+        part.add_attribute(
+            Attribute::new("value", net)
+                .origin(0.0, -2.5)
+                .align(HAlign::Center, VAlign::Top),
+        );
+        part.add_line(Line::new(0.0, 0.0, 0.0, -1.0));
+        match variant {
+            "signal" => {
+                let triangle = Polyline::new(vec![
+                    Point::new(-1.0, -1.0),
+                    Point::new(1.0, -1.0),
+                    Point::new(0.0, -2.0),
+                ]);
+                part.add_lines(triangle.closed().to_lines());
+            }
+            "chassis" => {
+                part.add_line(Line::new(-1.0, -1.0, 1.0, -1.0));
+                for &x in &[-1.0, -0.4, 0.2] {
+                    part.add_line(Line::new(x, -1.0, x + 0.6, -1.6));
+                }
+            }
+            "earth" => {
+                for &(half, y) in &[(1.0, -1.0), (0.6, -1.4), (0.2, -1.8)] {
+                    part.add_line(Line::new(-half, y, half, y));
+                }
+            }
+            _ => bail!(QedaError::InvalidSymbolVariant(variant.to_string())),
+        }
+        part.add_symbol_pin(power_pin(net, VAlign::Top));
+
+        let mut result = Symbol::new();
+        result.add_part(part);
+        result.power = true;
+        Ok(result)
+    }
+}
@@ -0,0 +1,427 @@
+use std::collections::HashSet;
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::SymbolHandler;
+
+// Place at the side of the box: indices of stacked pins with the same name or a gap
+type Slot = Option<Vec<usize>>;
+
+/// Pin slots at the box sides.
+pub struct Sides {
+    left: Vec<Slot>,
+    right: Vec<Slot>,
+    top: Vec<Slot>,
+    bottom: Vec<Slot>,
+}
+
+/// Settings of the box symbol taken from the library config.
+pub struct BoxSettings {
+    pitch: f64,
+    grid: f64,
+    font_size: f64,
+    space: f64,
+    space_pin: f64,
+    space_attribute: f64,
+}
+
+impl BoxSettings {
+    pub fn new(lib_cfg: &Config) -> Self {
+        BoxSettings {
+            pitch: lib_cfg.get_f64("symbol.pitch").unwrap(),
+            grid: lib_cfg.get_f64("symbol.grid").unwrap(),
+            font_size: lib_cfg.get_f64("symbol.font-size.pin").unwrap(),
+            space: lib_cfg.get_f64("symbol.space.default").unwrap(),
+            space_pin: lib_cfg.get_f64("symbol.space.pin").unwrap(),
+            space_attribute: lib_cfg.get_f64("symbol.space.attribute").unwrap(),
+        }
+    }
+
+    // Round up to the grid
+    fn ceil(&self, v: f64) -> f64 {
+        (v / self.grid - 1e-9).ceil() * self.grid
+    }
+}
+
+/// Returns top-level pin groups in the order of appearance.
+///
+/// Pins of a nested pinout group form a single unit, other pins are united by name.
+pub fn units(pinout: &Pinout) -> Vec<(String, Vec<usize>)> {
+    let containers: Vec<(&String, &Vec<usize>)> = pinout
+        .groups
+        .iter()
+        .filter(|(name, indices)| indices.iter().any(|&i| pinout.pins[i].name != **name))
+        .collect();
+    let mut result: Vec<(String, Vec<usize>)> = Vec::new();
+    for (i, pin) in pinout.pins.iter().enumerate() {
+        let (name, indices) = containers
+            .iter()
+            .filter(|(_, indices)| indices.contains(&i))
+            .max_by_key(|(_, indices)| indices.len())
+            .map(|&(name, indices)| (name, indices))
+            .unwrap_or((&pin.name, &pinout.groups[&pin.name]));
+        if !result.iter().any(|(n, _)| n == name) {
+            result.push((name.clone(), indices.clone()));
+        }
+    }
+    result
+}
+
+// Convert pin indices to slots skipping names already placed and pins of other parts
+fn slots(
+    pinout: &Pinout,
+    indices: &[usize],
+    part: &[usize],
+    placed: &mut HashSet<String>,
+) -> Vec<Slot> {
+    let mut result = Vec::new();
+    for &i in indices.iter().filter(|i| part.contains(i)) {
+        let name = &pinout.pins[i].name;
+        if placed.insert(name.clone()) {
+            let stacked = pinout.groups[name]
+                .iter()
+                .filter(|i| part.contains(i))
+                .cloned()
+                .collect();
+            result.push(Some(stacked));
+        }
+    }
+    result
+}
+
+// Concatenate units separating multi-pin ones with gaps
+fn flatten(units: Vec<Vec<Slot>>) -> Vec<Slot> {
+    let mut result: Vec<Slot> = Vec::new();
+    let mut prev_len = 0;
+    for unit in units.into_iter().filter(|u| !u.is_empty()) {
+        if !result.is_empty() && (prev_len > 1 || unit.len() > 1) {
+            result.push(None);
+        }
+        prev_len = unit.len();
+        result.extend(unit);
+    }
+    // Gaps make sense only between pins, e.g. when some of them belong to another part
+    while let Some(None) = result.last() {
+        result.pop();
+    }
+    while let Some(None) = result.first() {
+        result.remove(0);
+    }
+    result
+}
+
+impl Sides {
+    /// Distributes pins to the box sides.
+    ///
+    /// Pins listed in `symbol.left`, `symbol.right`, `symbol.top` and `symbol.bottom`
+    /// are placed first (`~` adds a gap). Remaining power pins go to the top, ground pins
+    /// go to the bottom and other ones are balanced between the left and right sides.
+    pub fn from_config(comp_cfg: &Config, pinout: &Pinout, indices: &[usize]) -> Result<Self> {
+        let mut placed = HashSet::new();
+        let mut sides: [Vec<Vec<Slot>>; 4] = Default::default();
+        for (side, key) in sides.iter_mut().zip(&["left", "right", "top", "bottom"]) {
+            let key = format!("symbol.{}", key);
+            let entries = match comp_cfg.get_element(&key) {
+                Ok(Value::Array(a)) => a.clone(),
+                Ok(v) => vec![v.clone()],
+                Err(_) => continue,
+            };
+            let mut explicit = Vec::new();
+            for entry in entries {
+                match entry {
+                    Value::Null => explicit.push(None),
+                    Value::String(name) => {
+                        let group = pinout
+                            .groups
+                            .get(&name)
+                            .ok_or(QedaError::InvalidPinName(name))?;
+                        explicit.append(&mut slots(pinout, group, indices, &mut placed));
+                    }
+                    _ => bail!(QedaError::InvalidElementType(key, "[string]")),
+                }
+            }
+            side.push(explicit);
+        }
+
+        let power = Regex::new(r"^[ADP]?(VCC|VDD|VBAT|VIO|V\+)").unwrap();
+        let ground = Regex::new(r"^[ADP]?(GND|VSS|VEE|V-)").unwrap();
+        let [left, right, top, bottom] = &mut sides;
+        for (name, unit) in units(pinout) {
+            let unit: Vec<usize> = unit.into_iter().filter(|i| indices.contains(i)).collect();
+            let kinds: Vec<PinKind> = unit.iter().map(|&i| pinout.pins[i].kind).collect();
+            let unit = slots(pinout, &unit, indices, &mut placed);
+            let count = |side: &Vec<Vec<Slot>>| side.iter().map(|u| u.len()).sum::<usize>();
+            if power.is_match(&name) {
+                top.push(unit);
+            } else if ground.is_match(&name) {
+                bottom.push(unit);
+            } else if kinds.iter().all(|k| *k == PinKind::OUT) {
+                right.push(unit);
+            } else if kinds.iter().all(|k| *k == PinKind::IN) || count(left) <= count(right) {
+                left.push(unit);
+            } else {
+                right.push(unit);
+            }
+        }
+
+        let [left, right, top, bottom] = sides;
+        Ok(Sides {
+            left: flatten(left),
+            right: flatten(right),
+            top: flatten(top),
+            bottom: flatten(bottom),
+        })
+    }
+
+    /// Draws a box with pins at its sides.
+    pub fn draw(&self, pinout: &Pinout, settings: &BoxSettings) -> Drawing {
+        let s = settings;
+        let pin_len = s.pitch;
+        let margin = s.ceil(s.pitch / 2.0);
+        let text_width = |slot: &Slot| match slot {
+            Some(indices) => pinout.pins[indices[0]].name.chars().count() as f64 * s.font_size,
+            None => 0.0,
+        };
+        // Space occupied by pin names inside the box
+        let extent = |slots: &[Slot]| {
+            if slots.iter().any(Option::is_some) {
+                slots.iter().map(text_width).fold(0.0, f64::max) + s.space_pin
+            } else {
+                0.0
+            }
+        };
+
+        let rows = self.left.len().max(self.right.len()).max(1) as f64;
+        let cols = self.top.len().max(self.bottom.len()).max(1) as f64;
+        let ext_top = s.ceil(extent(&self.top));
+        let ext_bottom = s.ceil(extent(&self.bottom));
+        let width = (extent(&self.left) + extent(&self.right) + s.space)
+            .max((cols - 1.0) * s.pitch + 2.0 * margin);
+        let half_width = s.ceil(width / 2.0);
+        let height = ext_top + (rows - 1.0) * s.pitch + 2.0 * margin + ext_bottom;
+        let top = s.ceil(height / 2.0);
+        let bottom = top - height;
+
+        let mut part = Drawing::new();
+        let ref_des_y = if self.top.is_empty() {
+            top
+        } else {
+            top + pin_len
+        };
+        part.add_attribute(
+            Attribute::new("ref-des", "U")
+                .origin(-half_width, ref_des_y + s.space_attribute)
+                .align(HAlign::Left, VAlign::Bottom),
+        );
+        let value_y = if self.bottom.is_empty() {
+            bottom
+        } else {
+            bottom - pin_len
+        };
+        part.add_attribute(
+            Attribute::new("value", "IC")
+                .origin(-half_width, value_y - s.space_attribute)
+                .align(HAlign::Left, VAlign::Top),
+        );
+        part.add_lines(Rect::new(-half_width, top, half_width, bottom).to_lines());
+
+        // Same-named pins are stacked, only the first one is visible
+        let mut add_pins = |slot: &Slot, halign: HAlign, valign: VAlign, line: Line| {
+            for (n, &i) in slot.iter().flatten().enumerate() {
+                let pin = pinout.pins[i].clone();
+                let mut sym_pin = SymbolPin::new(pin, halign.clone(), valign.clone(), &line);
+                sym_pin.visibility = Visibility(n == 0);
+                part.add_symbol_pin(sym_pin);
+            }
+        };
+        let first_row = top - ext_top - margin;
+        for (i, slot) in self.left.iter().enumerate() {
+            let y = first_row - i as f64 * s.pitch;
+            let line = Line::new(-half_width - pin_len, y, -half_width, y);
+            add_pins(slot, HAlign::Left, VAlign::Middle, line);
+        }
+        for (i, slot) in self.right.iter().enumerate() {
+            let y = first_row - i as f64 * s.pitch;
+            let line = Line::new(half_width, y, half_width + pin_len, y);
+            add_pins(slot, HAlign::Right, VAlign::Middle, line);
+        }
+        for &(slots, y, dy, ref valign) in &[
+            (&self.top, top, pin_len, VAlign::Top),
+            (&self.bottom, bottom, -pin_len, VAlign::Bottom),
+        ] {
+            let first_col = -s.ceil((slots.len() as f64 - 1.0) * s.pitch / 2.0);
+            for (i, slot) in slots.iter().enumerate() {
+                let x = first_col + i as f64 * s.pitch;
+                let line = Line::new(x, y, x, y + dy);
+                add_pins(slot, HAlign::Center, valign.clone(), line);
+            }
+        }
+
+        part
+    }
+}
+
+/// Returns pin indices of the symbol parts.
+///
+/// Parts are listed in `symbol.parts` by pin group names, e.g. `[[GPIOA], [GPIOB], [POWER]]`.
+/// Pins not mentioned there are collected into an additional part.
+pub fn parts(comp_cfg: &Config, pinout: &Pinout) -> Result<Vec<Vec<usize>>> {
+    let entries = match comp_cfg.get_element("symbol.parts") {
+        Ok(Value::Array(a)) => a,
+        Ok(_) => bail!(QedaError::InvalidElementType(
+            "symbol.parts".to_string(),
+            "[[string]]"
+        )),
+        Err(_) => return Ok(vec![(0..pinout.pins.len()).collect()]),
+    };
+
+    let mut result: Vec<Vec<usize>> = Vec::new();
+    let mut used = HashSet::new();
+    for entry in entries {
+        let names = match entry {
+            Value::Array(a) => a.iter().collect(),
+            Value::String(_) => vec![entry],
+            _ => bail!(QedaError::InvalidElementType(
+                "symbol.parts".to_string(),
+                "[[string]]"
+            )),
+        };
+        let mut part = Vec::new();
+        for name in names {
+            let name = name.as_str().ok_or_else(|| {
+                QedaError::InvalidElementType("symbol.parts".to_string(), "[[string]]")
+            })?;
+            let group = pinout
+                .groups
+                .get(name)
+                .ok_or_else(|| QedaError::InvalidPinName(name.to_string()))?;
+            part.extend(group.iter().filter(|&&i| used.insert(i)));
+        }
+        if !part.is_empty() {
+            result.push(part);
+        }
+    }
+    let rest: Vec<usize> = (0..pinout.pins.len())
+        .filter(|i| !used.contains(i))
+        .collect();
+    if !rest.is_empty() {
+        result.push(rest);
+    }
+    Ok(result)
+}
+
+pub struct IcSymbol {}
+
+impl IcSymbol {
+    pub fn new() -> IcSymbol {
+        IcSymbol {}
+    }
+}
+
+impl SymbolHandler for IcSymbol {
+    fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw IC symbol");
+
+        let settings = BoxSettings::new(lib_cfg);
+        let pinout = Pinout::from_config(comp_cfg)?;
+
+        let mut result = Symbol::new();
+        result.show_pin_numbers = true;
+        result.show_pin_names = true;
+        for indices in parts(comp_cfg, &pinout)? {
+            let sides = Sides::from_config(comp_cfg, &pinout, &indices)?;
+            result.add_part(sides.draw(&pinout, &settings));
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sides() -> Result<()> {
+        let comp_cfg = Config::from_yaml(
+            r"
+        symbol:
+          right: [PB, ~, RESET]
+        pinout:
+          VCC: 1
+          PA:
+            PA0..PA1: 2..3
+          PB:
+            PB0..PB1: 4..5
+          RESET: 6
+          GND: [7, 8]
+          XTAL: 9
+        ",
+        )?;
+        let pinout = Pinout::from_config(&comp_cfg)?;
+        let indices: Vec<usize> = (0..pinout.pins.len()).collect();
+        let sides = Sides::from_config(&comp_cfg, &pinout, &indices)?;
+        let names = |slots: &[Slot]| -> Vec<String> {
+            slots
+                .iter()
+                .map(|slot| match slot {
+                    Some(indices) => pinout.pins[indices[0]].name.clone(),
+                    None => "~".to_string(),
+                })
+                .collect()
+        };
+        assert_eq!(names(&sides.left), vec!["PA0", "PA1", "~", "XTAL"]);
+        assert_eq!(names(&sides.right), vec!["PB0", "PB1", "~", "RESET"]);
+        assert_eq!(names(&sides.top), vec!["VCC"]);
+        assert_eq!(names(&sides.bottom), vec!["GND"]);
+        let gnd: Vec<&str> = sides.bottom[0]
+            .iter()
+            .flatten()
+            .map(|&i| pinout.pins[i].number.as_str())
+            .collect();
+        assert_eq!(gnd, vec!["7", "8"]);
+        Ok(())
+    }
+
+    #[test]
+    fn parts() -> Result<()> {
+        let comp_cfg = Config::from_yaml(
+            r"
+        symbol:
+          parts: [[A], B]
+        pinout:
+          A:
+            OUTA: 1
+            INA: 2
+          B:
+            OUTB: 3
+            INB: 4
+          VCC: 5
+          GND: 6
+        ",
+        )?;
+        let pinout = Pinout::from_config(&comp_cfg)?;
+        let numbers: Vec<Vec<&str>> = super::parts(&comp_cfg, &pinout)?
+            .iter()
+            .map(|part| {
+                let mut numbers: Vec<&str> = part
+                    .iter()
+                    .map(|&i| pinout.pins[i].number.as_str())
+                    .collect();
+                numbers.sort_unstable();
+                numbers
+            })
+            .collect();
+        assert_eq!(
+            numbers,
+            vec![vec!["1", "2"], vec!["3", "4"], vec!["5", "6"]]
+        );
+        Ok(())
+    }
+}
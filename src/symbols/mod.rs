@@ -1,4 +1,18 @@
 mod capacitor;
+mod connector;
+mod diode;
+mod ferrite;
+mod fet;
+mod ground;
+mod ic;
+mod inductor;
+mod mounting_hole;
+mod power;
+mod resistor;
+mod test_point;
+mod transformer;
+mod transistor;
+mod twin_diode;
 
 use std::collections::HashMap;
 use std::fmt::{self, Debug};
@@ -8,6 +22,20 @@ use crate::error::*;
 use crate::symbol::Symbol;
 
 use capacitor::CapacitorSymbol;
+use connector::ConnectorSymbol;
+use diode::{DiodeSymbol, DiodeVariant};
+use ferrite::FerriteSymbol;
+use fet::FetSymbol;
+use ground::GroundSymbol;
+use ic::IcSymbol;
+use inductor::InductorSymbol;
+use mounting_hole::MountingHoleSymbol;
+use power::PowerSymbol;
+use resistor::ResistorSymbol;
+use test_point::TestPointSymbol;
+use transformer::TransformerSymbol;
+use transistor::TransistorSymbol;
+use twin_diode::TwinDiodeSymbol;
 
 pub trait SymbolHandler {
     fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol>;
@@ -29,6 +57,36 @@ impl Symbols {
     pub fn new() -> Symbols {
         let mut handlers: HashMap<&'static str, Box<dyn SymbolHandler>> = HashMap::new();
         handlers.insert("capacitor", Box::new(CapacitorSymbol::new()));
+        handlers.insert("connector", Box::new(ConnectorSymbol::new()));
+        handlers.insert("diode", Box::new(DiodeSymbol::new()));
+        handlers.insert("ferrite", Box::new(FerriteSymbol::new()));
+        handlers.insert("fet", Box::new(FetSymbol::new()));
+        handlers.insert("ground", Box::new(GroundSymbol::new()));
+        handlers.insert("ic", Box::new(IcSymbol::new()));
+        handlers.insert("inductor", Box::new(InductorSymbol::new()));
+        handlers.insert(
+            "led",
+            Box::new(DiodeSymbol::new().variant(DiodeVariant::Led)),
+        );
+        handlers.insert("mounting-hole", Box::new(MountingHoleSymbol::new()));
+        handlers.insert("power", Box::new(PowerSymbol::new()));
+        handlers.insert("resistor", Box::new(ResistorSymbol::new()));
+        handlers.insert(
+            "schottky",
+            Box::new(DiodeSymbol::new().variant(DiodeVariant::Schottky)),
+        );
+        handlers.insert("test-point", Box::new(TestPointSymbol::new()));
+        handlers.insert("transformer", Box::new(TransformerSymbol::new()));
+        handlers.insert("transistor", Box::new(TransistorSymbol::new()));
+        handlers.insert(
+            "tvs",
+            Box::new(DiodeSymbol::new().variant(DiodeVariant::Tvs)),
+        );
+        handlers.insert("twin-diode", Box::new(TwinDiodeSymbol::new()));
+        handlers.insert(
+            "zener",
+            Box::new(DiodeSymbol::new().variant(DiodeVariant::Zener)),
+        );
 
         Symbols { handlers }
     }
@@ -0,0 +1,54 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::SymbolHandler;
+
+pub struct MountingHoleSymbol {}
+
+impl MountingHoleSymbol {
+    pub fn new() -> MountingHoleSymbol {
+        MountingHoleSymbol {}
+    }
+}
+
+impl SymbolHandler for MountingHoleSymbol {
+    fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw mounting hole symbol");
+
+        let pitch = lib_cfg.get_f64("symbol.pitch").unwrap();
+        let plated = comp_cfg.get_bool("package.plated").unwrap_or(true);
+        let radius = 1.0;
+
+        let mut part = Drawing::new();
+        part.add_attribute(
+            Attribute::new("ref-des", "H")
+                .origin(radius + 0.5, 0.5)
+                .align(HAlign::Left, VAlign::Bottom),
+        );
+        part.add_attribute(
+            Attribute::new("value", "MountingHole")
+                .origin(radius + 0.5, -0.5)
+                .align(HAlign::Left, VAlign::Top),
+        );
+        part.add_circle(Circle::new(0.0, 0.0, radius));
+
+        // Only a plated hole can be connected
+        if plated {
+            let pinout = Pinout::from_config(comp_cfg)?;
+            let pin = pinout
+                .pins
+                .first()
+                .cloned()
+                .unwrap_or_else(|| Pin::new("1", "1").kind(PinKind::PASSIVE));
+            let line = Line::new(0.0, -radius, 0.0, -radius - pitch);
+            part.add_symbol_pin(SymbolPin::new(pin, HAlign::Center, VAlign::Bottom, &line));
+        }
+
+        let mut result = Symbol::new();
+        result.add_part(part);
+        Ok(result)
+    }
+}
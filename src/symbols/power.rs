@@ -0,0 +1,53 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::SymbolHandler;
+
+/// Returns a hidden zero-length power input pin named after the net.
+pub fn power_pin(net: &str, valign: VAlign) -> SymbolPin {
+    let pin = Pin::new(net, "1").kind(PinKind::POWER | PinKind::IN);
+    let line = Line::new(0.0, 0.0, 0.0, 0.0);
+    let mut result = SymbolPin::new(pin, HAlign::Center, valign, &line);
+    result.visibility = Visibility(false);
+    result
+}
+
+pub struct PowerSymbol {}
+
+impl PowerSymbol {
+    pub fn new() -> PowerSymbol {
+        PowerSymbol {}
+    }
+}
+
+impl SymbolHandler for PowerSymbol {
+    fn draw(&self, comp_cfg: &Config, _lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw power symbol");
+
+        let net = comp_cfg.get_str("name")?;
+
+        let mut part = Drawing::new();
+        part.add_attribute(
+            Attribute::new("ref-des", "#PWR")
+                .origin(0.0, -1.0)
+                .align(HAlign::Center, VAlign::Top)
+                .visibility(Visibility(false)),
+        );
+        part.add_attribute(
+            Attribute::new("value", net)
+                .origin(0.0, 1.5)
+                .align(HAlign::Center, VAlign::Bottom),
+        );
+        part.add_line(Line::new(0.0, 0.0, 0.0, 1.0));
+        part.add_line(Line::new(-0.5, 1.0, 0.5, 1.0));
+        part.add_symbol_pin(power_pin(net, VAlign::Bottom));
+
+        let mut result = Symbol::new();
+        result.add_part(part);
+        result.power = true;
+        Ok(result)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::config::Config;
+use crate::drawing::Drawing;
+use crate::error::*;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::SymbolHandler;
+
+pub struct ResistorSymbol {}
+
+impl ResistorSymbol {
+    pub fn new() -> ResistorSymbol {
+        ResistorSymbol {}
+    }
+}
+
+impl SymbolHandler for ResistorSymbol {
+    fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw resistor symbol");
+
+        let style = comp_cfg
+            .get_str("symbol.style")
+            .or_else(|_| lib_cfg.get_str("symbol.style"))
+            .unwrap_or("IEC");
+        let svg = match style.to_uppercase().as_str() {
+            "IEC" => include_str!("resistor-iec.svg"),
+            "ANSI" => include_str!("resistor-ansi.svg"),
+            _ => bail!(QedaError::InvalidSymbolStyle(style.to_string())),
+        };
+
+        let mut pinout = Pinout::from_config(comp_cfg)?;
+        if !pinout.groups.contains_key("L") {
+            pinout.add_pin(Pin::new("L", "1").kind(PinKind::PASSIVE));
+        }
+        if !pinout.groups.contains_key("R") {
+            pinout.add_pin(Pin::new("R", "2").kind(PinKind::PASSIVE));
+        }
+
+        let mut result = Symbol::new();
+        result.add_part(Drawing::from_svg(svg, pinout)?);
+        Ok(result)
+    }
+}
@@ -0,0 +1,50 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::SymbolHandler;
+
+pub struct TestPointSymbol {}
+
+impl TestPointSymbol {
+    pub fn new() -> TestPointSymbol {
+        TestPointSymbol {}
+    }
+}
+
+impl SymbolHandler for TestPointSymbol {
+    fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw test point symbol");
+
+        let pitch = lib_cfg.get_f64("symbol.pitch").unwrap();
+        let pinout = Pinout::from_config(comp_cfg)?;
+        let radius = 0.5;
+
+        let mut part = Drawing::new();
+        part.add_attribute(
+            Attribute::new("ref-des", "TP")
+                .origin(radius + 0.5, 0.5)
+                .align(HAlign::Left, VAlign::Bottom),
+        );
+        part.add_attribute(
+            Attribute::new("value", "TestPoint")
+                .origin(radius + 0.5, -0.5)
+                .align(HAlign::Left, VAlign::Top),
+        );
+        part.add_circle(Circle::new(0.0, 0.0, radius));
+
+        let pin = pinout
+            .pins
+            .first()
+            .cloned()
+            .unwrap_or_else(|| Pin::new("1", "1").kind(PinKind::PASSIVE));
+        let line = Line::new(0.0, -radius, 0.0, -radius - pitch);
+        part.add_symbol_pin(SymbolPin::new(pin, HAlign::Center, VAlign::Bottom, &line));
+
+        let mut result = Symbol::new();
+        result.add_part(part);
+        Ok(result)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::SymbolHandler;
+
+pub struct TransformerSymbol {}
+
+impl TransformerSymbol {
+    pub fn new() -> TransformerSymbol {
+        TransformerSymbol {}
+    }
+}
+
+impl SymbolHandler for TransformerSymbol {
+    fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw transformer symbol");
+
+        let pitch = lib_cfg.get_f64("symbol.pitch").unwrap();
+        let pinout = Pinout::from_config(comp_cfg)?;
+        let primary = comp_cfg.get_u64("symbol.primary").unwrap_or(1) as usize;
+        let secondary = comp_cfg.get_u64("symbol.secondary").unwrap_or(1) as usize;
+        let turns = comp_cfg.get_u64("symbol.turns").unwrap_or(4).max(1) as usize;
+        let dots = comp_cfg.get_bool("symbol.dots").unwrap_or(true);
+        let core = comp_cfg.get_bool("symbol.core").unwrap_or(true);
+
+        let radius = 0.5; // Each turn is one grid unit high
+        let winding_x = 1.5;
+        let pin_len = 1.5;
+        let core_x = 0.25;
+        let winding_len = turns as f64 * 2.0 * radius;
+        let stack_len =
+            |count: usize| count as f64 * winding_len + count.saturating_sub(1) as f64 * pitch;
+        let top = (stack_len(primary.max(secondary)) / 2.0).ceil();
+        let bottom = top - stack_len(primary.max(secondary));
+
+        let mut part = Drawing::new();
+        part.add_attribute(
+            Attribute::new("ref-des", "T")
+                .origin(0.0, top + 1.0)
+                .align(HAlign::Center, VAlign::Bottom),
+        );
+        part.add_attribute(
+            Attribute::new("value", "Transformer")
+                .origin(0.0, bottom - 1.0)
+                .align(HAlign::Center, VAlign::Top),
+        );
+        if core {
+            part.add_line(Line::new(-core_x, top, -core_x, bottom));
+            part.add_line(Line::new(core_x, top, core_x, bottom));
+        }
+
+        let pin = |number: usize| {
+            let number = number.to_string();
+            let pin = pinout
+                .pins
+                .iter()
+                .find(|pin| pin.number == number)
+                .cloned()
+                .unwrap_or_else(|| Pin::new(&number, &number));
+            if pin.kind == PinKind::UNSPECIFIED {
+                pin.kind(PinKind::PASSIVE)
+            } else {
+                pin
+            }
+        };
+
+        // Primary windings are at the left side, secondary ones are at the right side.
+        // Each winding has the dotted start pin at the top and the end pin at the bottom.
+        let mut number = 1;
+        for &(count, side) in &[(primary, -1.0), (secondary, 1.0)] {
+            let x = side * winding_x;
+            let (start, end) = if side < 0.0 {
+                (-90.0, 90.0)
+            } else {
+                (90.0, 270.0)
+            };
+            let mut y = (stack_len(count) / 2.0).ceil();
+            for _ in 0..count {
+                for turn in 0..turns {
+                    let cy = y - radius - turn as f64 * 2.0 * radius;
+                    part.add_arc(Arc::new(x, cy, radius, start, end));
+                }
+                if dots {
+                    part.add_circle(Circle::new(x + side * radius, y - radius, 0.15).filled());
+                }
+                for &pin_y in &[y, y - winding_len] {
+                    let line = Line::new(x + side * pin_len, pin_y, x, pin_y);
+                    let halign = if side < 0.0 {
+                        HAlign::Left
+                    } else {
+                        HAlign::Right
+                    };
+                    part.add_symbol_pin(SymbolPin::new(pin(number), halign, VAlign::Middle, &line));
+                    number += 1;
+                }
+                y -= winding_len + pitch;
+            }
+        }
+
+        let mut result = Symbol::new();
+        result.show_pin_numbers = true;
+        result.add_part(part);
+        Ok(result)
+    }
+}
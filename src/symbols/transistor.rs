@@ -0,0 +1,105 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::SymbolHandler;
+
+/// Returns lines of an open arrowhead with the tip at `(x, y)` pointing to `(dx, dy)`.
+pub fn arrow(x: f64, y: f64, dx: f64, dy: f64) -> Vec<Line> {
+    let len = 0.4;
+    let norm = (dx * dx + dy * dy).sqrt();
+    let (dx, dy) = (dx / norm, dy / norm);
+    let (sin, cos) = 25f64.to_radians().sin_cos();
+    vec![
+        Line::new(
+            x,
+            y,
+            x - len * (dx * cos - dy * sin),
+            y - len * (dx * sin + dy * cos),
+        ),
+        Line::new(
+            x,
+            y,
+            x - len * (dx * cos + dy * sin),
+            y - len * (dy * cos - dx * sin),
+        ),
+    ]
+}
+
+/// Returns the first pin of the group that must be present in the component's pinout.
+pub fn required_pin(pinout: &Pinout, name: &str) -> Result<Pin> {
+    let pin = pinout
+        .get_first(name)
+        .cloned()
+        .ok_or_else(|| QedaError::MissingElement(format!("pinout.{}", name)))?;
+    if pin.kind == PinKind::UNSPECIFIED {
+        Ok(pin.kind(PinKind::PASSIVE))
+    } else {
+        Ok(pin)
+    }
+}
+
+/// Returns a vertical pin going from `(x, y)` outwards of the horizontal axis.
+pub fn vertical_pin(pin: Pin, x: f64, y: f64, length: f64) -> SymbolPin {
+    let line = Line::new(x, y, x, y + y.signum() * length);
+    let valign = if y > 0.0 { VAlign::Top } else { VAlign::Bottom };
+    SymbolPin::new(pin, HAlign::Center, valign, &line)
+}
+
+pub struct TransistorSymbol {}
+
+impl TransistorSymbol {
+    pub fn new() -> TransistorSymbol {
+        TransistorSymbol {}
+    }
+}
+
+impl SymbolHandler for TransistorSymbol {
+    fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw transistor symbol");
+
+        let pitch = lib_cfg.get_f64("symbol.pitch").unwrap();
+        let variant = comp_cfg.get_str("symbol.variant").unwrap_or("npn");
+        // PNP is drawn upside down to keep the emitter at the top
+        let sy = match variant {
+            "npn" => 1.0,
+            "pnp" => -1.0,
+            _ => bail!(QedaError::InvalidSymbolVariant(variant.to_string())),
+        };
+        let pinout = Pinout::from_config(comp_cfg)?;
+        let base = required_pin(&pinout, "B")?;
+        let collector = required_pin(&pinout, "C")?;
+        let emitter = required_pin(&pinout, "E")?;
+
+        let mut part = Drawing::new();
+        part.add_attribute(
+            Attribute::new("ref-des", "Q")
+                .origin(2.0, 0.5)
+                .align(HAlign::Left, VAlign::Bottom),
+        );
+        part.add_attribute(
+            Attribute::new("value", "Transistor")
+                .origin(2.0, -0.5)
+                .align(HAlign::Left, VAlign::Top),
+        );
+        part.add_line(Line::new(0.0, -1.0, 0.0, 1.0));
+        part.add_line(Line::new(0.0, 0.5 * sy, 1.0, sy));
+        part.add_line(Line::new(0.0, -0.5 * sy, 1.0, -sy));
+        if sy > 0.0 {
+            part.add_lines(arrow(0.8, -0.9, 1.0, -0.5));
+        } else {
+            part.add_lines(arrow(0.2, 0.6, -1.0, -0.5));
+        }
+
+        let line = Line::new(-pitch, 0.0, 0.0, 0.0);
+        part.add_symbol_pin(SymbolPin::new(base, HAlign::Left, VAlign::Middle, &line));
+        part.add_symbol_pin(vertical_pin(collector, 1.0, sy, pitch));
+        part.add_symbol_pin(vertical_pin(emitter, 1.0, -sy, pitch));
+
+        let mut result = Symbol::new();
+        result.add_part(part);
+        Ok(result)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::config::Config;
+use crate::drawing::*;
+use crate::error::*;
+use crate::pinout::*;
+use crate::symbol::Symbol;
+
+use super::diode::{passive, DiodeVariant};
+use super::SymbolHandler;
+
+pub struct TwinDiodeSymbol {}
+
+impl TwinDiodeSymbol {
+    pub fn new() -> TwinDiodeSymbol {
+        TwinDiodeSymbol {}
+    }
+}
+
+impl SymbolHandler for TwinDiodeSymbol {
+    fn draw(&self, comp_cfg: &Config, lib_cfg: &Config) -> Result<Symbol> {
+        debug!("draw twin diode symbol");
+
+        let pitch = lib_cfg.get_f64("symbol.pitch").unwrap();
+        let variant = DiodeVariant::from_config(comp_cfg, DiodeVariant::Rectifier)?;
+        let common = comp_cfg.get_str("symbol.common").unwrap_or("cathode");
+        let (outer, inner, dir) = match common {
+            "cathode" => ("A", "K", 1.0),
+            "anode" => ("K", "A", -1.0),
+            _ => bail!(QedaError::InvalidSymbolVariant(common.to_string())),
+        };
+        let names = [
+            format!("{}1", outer),
+            format!("{}2", outer),
+            inner.to_string(),
+        ];
+
+        let mut pinout = Pinout::from_config(comp_cfg)?;
+        for (i, name) in names.iter().enumerate() {
+            if !pinout.groups.contains_key(name) {
+                pinout.add_pin(Pin::new(name, &(i + 1).to_string()).kind(PinKind::PASSIVE));
+            }
+        }
+
+        // Both diodes are at the same axis with the common pin in the middle
+        let half = variant.half_length();
+        let cx = half + 1.0;
+
+        let mut part = Drawing::new();
+        part.add_attribute(
+            Attribute::new("ref-des", "D")
+                .origin(0.0, variant.top() + 1.0)
+                .align(HAlign::Center, VAlign::Bottom),
+        );
+        part.add_attribute(
+            Attribute::new("value", "TwinDiode")
+                .origin(0.0, -pitch - 1.0)
+                .align(HAlign::Center, VAlign::Top),
+        );
+        part.add_lines(variant.lines(-cx, dir));
+        part.add_lines(variant.lines(cx, -dir));
+        part.add_line(Line::new(-cx + half, 0.0, cx - half, 0.0));
+
+        let line = Line::new(-cx - half - pitch, 0.0, -cx - half, 0.0);
+        let pin = passive(&pinout, &names[0]);
+        part.add_symbol_pin(SymbolPin::new(pin, HAlign::Left, VAlign::Middle, &line));
+        let line = Line::new(cx + half, 0.0, cx + half + pitch, 0.0);
+        let pin = passive(&pinout, &names[1]);
+        part.add_symbol_pin(SymbolPin::new(pin, HAlign::Right, VAlign::Middle, &line));
+        let line = Line::new(0.0, -pitch, 0.0, 0.0);
+        let pin = passive(&pinout, &names[2]);
+        part.add_symbol_pin(SymbolPin::new(pin, HAlign::Center, VAlign::Bottom, &line));
+
+        let mut result = Symbol::new();
+        result.add_part(part);
+        Ok(result)
+    }
+}